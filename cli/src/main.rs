@@ -1,22 +1,60 @@
 use anyhow::anyhow;
 use ethabi::{
-	decode, encode,
+	decode, encode, long_signature,
 	param_type::{ParamType, Reader},
-	token::{LenientTokenizer, StrictTokenizer, Token, Tokenizer},
-	Contract, Event, Function, Hash,
+	short_signature,
+	token::{tokens_from_json, LenientTokenizer, StrictTokenizer, Token, Tokenizer},
+	Address, Artifact, Bytecode, Constructor, Contract, Event, Function, Hash, Log, LogDecoder, LogEntry, Param,
+	RawLog, Revert, StateMutability,
 };
 use itertools::Itertools;
+use output::Format;
+use serde_json::{json, Value};
 use sha3::{Digest, Keccak256};
-use std::fs::File;
+use std::{
+	fs::{self, File},
+	io::{self, Read},
+	path::Path,
+};
 use structopt::StructOpt;
 
+mod output;
+mod repl;
+
 #[derive(StructOpt, Debug)]
 /// Ethereum ABI coder.
-enum Opt {
+struct Opt {
+	/// Print structured JSON instead of text.
+	#[structopt(long, global = true)]
+	json: bool,
+	/// Print integers in `hex` (default) or `decimal`, and addresses with their EIP-55 `checksum`.
+	#[structopt(long, global = true, use_delimiter = true, possible_values = &["hex", "decimal", "checksum"])]
+	format: Vec<String>,
+	#[structopt(subcommand)]
+	command: Command,
+}
+
+#[derive(StructOpt, Debug)]
+enum Command {
 	/// Encode ABI call.
 	Encode(Encode),
 	/// Decode ABI call result.
 	Decode(Decode),
+	/// Compute the selector of a function or error signature.
+	Selector { signature: String },
+	/// Compute the topic of an event signature.
+	Topic { signature: String },
+	/// List the selectors and topics of every function, event and error in an ABI.
+	Selectors { abi_path: String },
+	/// Explore an ABI interactively, encoding and decoding calls, logs and reverts.
+	Repl { abi_path: String },
+	/// Generate the Rust bindings `use_contract!` would, as a standalone module.
+	Codegen {
+		abi_path: String,
+		/// File to write the bindings to, printed when omitted.
+		#[structopt(short, long)]
+		output: Option<String>,
+	},
 }
 
 #[derive(StructOpt, Debug)]
@@ -27,6 +65,27 @@ enum Encode {
 		function_name_or_signature: String,
 		#[structopt(short, number_of_values = 1)]
 		params: Vec<String>,
+		/// JSON file with the params as an array, or an object keyed by param name, `-` for stdin.
+		#[structopt(short = "f", long, conflicts_with = "params")]
+		params_file: Option<String>,
+		/// Allow short representation of input params.
+		#[structopt(short, long)]
+		lenient: bool,
+	},
+	/// Encode contract deployment data, the creation bytecode followed by the constructor arguments.
+	Constructor {
+		/// JSON ABI file or compiler artifact.
+		abi_path: String,
+		/// File with hex encoded creation bytecode, defaults to the bytecode of the artifact.
+		///
+		/// Without any known bytecode only the encoded arguments are printed.
+		#[structopt(short, long)]
+		bytecode: Option<String>,
+		#[structopt(short, number_of_values = 1)]
+		params: Vec<String>,
+		/// JSON file with the params as an array, or an object keyed by param name, `-` for stdin.
+		#[structopt(short = "f", long, conflicts_with = "params")]
+		params_file: Option<String>,
 		/// Allow short representation of input params.
 		#[structopt(short, long)]
 		lenient: bool,
@@ -41,18 +100,41 @@ enum Encode {
 		/// Allow short representation of input params (numbers are in decimal form).
 		#[structopt(short, long)]
 		lenient: bool,
+		/// Print the keccak256 hash of the encoded params.
+		#[structopt(long)]
+		keccak: bool,
+	},
+	/// Encode params inline the way Solidity's `abi.encodePacked` does.
+	Packed {
+		/// Pairs of types directly followed by params in the form:
+		///
+		/// -v <type1> <param1> -v <type2> <param2> ...
+		#[structopt(short = "v", name = "type-or-param", number_of_values = 2, allow_hyphen_values = true)]
+		params: Vec<String>,
+		/// Allow short representation of input params (numbers are in decimal form).
+		#[structopt(short, long)]
+		lenient: bool,
+		/// Print the keccak256 hash of the encoded params.
+		#[structopt(long)]
+		keccak: bool,
 	},
 }
 
 #[derive(StructOpt, Debug)]
 enum Decode {
 	/// Load function from JSON ABI file.
-	Function { abi_path: String, function_name_or_signature: String, data: String },
+	Function {
+		abi_path: String,
+		function_name_or_signature: String,
+		/// Hex encoded data, read from stdin when omitted or `-`.
+		data: Option<String>,
+	},
 	/// Specify types of input params inline.
 	Params {
 		#[structopt(short, name = "type", number_of_values = 1)]
 		types: Vec<String>,
-		data: String,
+		/// Hex encoded data, read from stdin when omitted or `-`.
+		data: Option<String>,
 	},
 	/// Decode event log.
 	Log {
@@ -60,12 +142,53 @@ enum Decode {
 		event_name_or_signature: String,
 		#[structopt(short = "l", name = "topic", number_of_values = 1)]
 		topics: Vec<String>,
-		data: String,
+		/// Hex encoded data, read from stdin when omitted or `-`.
+		data: Option<String>,
+	},
+	/// Decode function call input, identifying the function by its selector.
+	Calldata {
+		/// JSON ABI file or compiler artifact, or a function signature like `transfer(address,uint256)`.
+		abi_path_or_signature: String,
+		/// Hex encoded data, read from stdin when omitted or `-`.
+		data: Option<String>,
+	},
+	/// Decode the logs of a JSON-RPC transaction receipt.
+	Receipt {
+		/// JSON ABI files or compiler artifacts with the events, `<path>@<address>` only decodes the
+		/// logs emitted by the contract at that address, given as 40 hex digits.
+		#[structopt(required = true, min_values = 1)]
+		abi_paths: Vec<String>,
+		/// JSON receipt, either bare or within a JSON-RPC response, `-` for stdin.
+		receipt_path: String,
+	},
+	/// Decode revert data of a failed call, recognizing `Error(string)`, `Panic(uint256)` and the
+	/// custom errors of an ABI.
+	Revert {
+		/// JSON ABI file or compiler artifact with the custom errors.
+		#[structopt(long = "abi")]
+		abi_path: Option<String>,
+		/// Hex encoded data, read from stdin when omitted or `-`.
+		data: Option<String>,
+	},
+	/// Decode constructor arguments from deployment data.
+	Constructor {
+		/// JSON ABI file or compiler artifact.
+		abi_path: String,
+		/// File with hex encoded creation bytecode, defaults to the bytecode of the artifact.
+		///
+		/// Without any known bytecode the arguments are searched for at the end of the data.
+		#[structopt(short, long)]
+		bytecode: Option<String>,
+		/// Hex encoded data, read from stdin when omitted or `-`.
+		data: Option<String>,
 	},
 }
 
 fn main() -> anyhow::Result<()> {
-	println!("{}", execute(std::env::args())?);
+	let output = execute(std::env::args())?;
+	if !output.is_empty() {
+		println!("{output}");
+	}
 
 	Ok(())
 }
@@ -75,26 +198,95 @@ where
 	I: IntoIterator,
 	I::Item: Into<std::ffi::OsString> + Clone,
 {
-	let opt = Opt::from_iter(args);
+	execute_with_stdin(args, &mut io::stdin())
+}
 
-	match opt {
-		Opt::Encode(Encode::Function { abi_path, function_name_or_signature, params, lenient }) => {
-			encode_input(&abi_path, &function_name_or_signature, &params, lenient)
+fn execute_with_stdin<I>(args: I, stdin: &mut dyn Read) -> anyhow::Result<String>
+where
+	I: IntoIterator,
+	I::Item: Into<std::ffi::OsString> + Clone,
+{
+	let Opt { json, format, command } = Opt::from_iter(args);
+	let format = Format::new(json, &format);
+
+	match command {
+		Command::Encode(Encode::Function { abi_path, function_name_or_signature, params, params_file, lenient }) => {
+			let params = match params_file {
+				Some(path) => Params::Json(serde_json::from_str(&read_input(&path, stdin)?)?),
+				None => Params::Args(params),
+			};
+			encode_input(&abi_path, &function_name_or_signature, &params, lenient, &format)
+		}
+		Command::Encode(Encode::Constructor { abi_path, bytecode, params, params_file, lenient }) => {
+			let params = match params_file {
+				Some(path) => Params::Json(serde_json::from_str(&read_input(&path, stdin)?)?),
+				None => Params::Args(params),
+			};
+			encode_constructor(&abi_path, bytecode.as_deref(), &params, lenient, &format)
+		}
+		Command::Encode(Encode::Params { params, lenient, keccak }) => encode_params(&params, lenient, keccak, &format),
+		Command::Encode(Encode::Packed { params, lenient, keccak }) => encode_packed(&params, lenient, keccak, &format),
+		Command::Decode(Decode::Function { abi_path, function_name_or_signature, data }) => {
+			decode_call_output(&abi_path, &function_name_or_signature, &read_data(data, stdin)?, &format)
+		}
+		Command::Decode(Decode::Params { types, data }) => decode_params(&types, &read_data(data, stdin)?, &format),
+		Command::Decode(Decode::Log { abi_path, event_name_or_signature, topics, data }) => {
+			decode_log(&abi_path, &event_name_or_signature, &topics, &read_data(data, stdin)?, &format)
+		}
+		Command::Decode(Decode::Calldata { abi_path_or_signature, data }) => {
+			decode_calldata(&abi_path_or_signature, &read_data(data, stdin)?, &format)
+		}
+		Command::Decode(Decode::Receipt { abi_paths, receipt_path }) => {
+			decode_receipt(&abi_paths, &read_input(&receipt_path, stdin)?, &format)
+		}
+		Command::Decode(Decode::Revert { abi_path, data }) => {
+			decode_revert(abi_path.as_deref(), &read_data(data, stdin)?, &format)
 		}
-		Opt::Encode(Encode::Params { params, lenient }) => encode_params(&params, lenient),
-		Opt::Decode(Decode::Function { abi_path, function_name_or_signature, data }) => {
-			decode_call_output(&abi_path, &function_name_or_signature, &data)
+		Command::Decode(Decode::Constructor { abi_path, bytecode, data }) => {
+			decode_constructor(&abi_path, bytecode.as_deref(), &read_data(data, stdin)?, &format)
 		}
-		Opt::Decode(Decode::Params { types, data }) => decode_params(&types, &data),
-		Opt::Decode(Decode::Log { abi_path, event_name_or_signature, topics, data }) => {
-			decode_log(&abi_path, &event_name_or_signature, &topics, &data)
+		Command::Selector { signature } => selector(&signature, &format),
+		Command::Topic { signature } => topic(&signature, &format),
+		Command::Selectors { abi_path } => selectors(&abi_path, &format),
+		Command::Repl { abi_path } => repl::run(load_contract(&abi_path)?, format),
+		Command::Codegen { abi_path, output } => {
+			let bindings = codegen(&abi_path)?;
+			match output {
+				Some(output) => fs::write(output, bindings).map(|_| String::new()).map_err(From::from),
+				None => Ok(bindings),
+			}
 		}
 	}
 }
 
-fn load_function(path: &str, name_or_signature: &str) -> anyhow::Result<Function> {
+/// Loads a contract from either a JSON ABI file or a compiler artifact.
+fn load_contract(path: &str) -> anyhow::Result<Contract> {
+	Ok(load_artifact(path)?.abi)
+}
+
+fn load_artifact(path: &str) -> anyhow::Result<Artifact> {
 	let file = File::open(path)?;
-	let contract = Contract::load(file)?;
+	Ok(Artifact::load(file)?)
+}
+
+/// Loads the constructor and, if known, the creation bytecode, preferring the `bytecode_path`
+/// file over the bytecode of the artifact.
+fn load_constructor(path: &str, bytecode_path: Option<&str>) -> anyhow::Result<(Constructor, Option<Bytecode>)> {
+	let artifact = load_artifact(path)?;
+	let constructor = artifact.abi.constructor.unwrap_or(Constructor { inputs: vec![] });
+	let bytecode = match bytecode_path {
+		Some(bytecode_path) => Some(fs::read_to_string(bytecode_path)?.trim().parse()?),
+		None => artifact.bytecode,
+	};
+
+	Ok((constructor, bytecode))
+}
+
+fn load_function(path: &str, name_or_signature: &str) -> anyhow::Result<Function> {
+	find_function(&load_contract(path)?, name_or_signature)
+}
+
+fn find_function(contract: &Contract, name_or_signature: &str) -> anyhow::Result<Function> {
 	let params_start = name_or_signature.find('(');
 
 	match params_start {
@@ -126,8 +318,10 @@ fn load_function(path: &str, name_or_signature: &str) -> anyhow::Result<Function
 }
 
 fn load_event(path: &str, name_or_signature: &str) -> anyhow::Result<Event> {
-	let file = File::open(path)?;
-	let contract = Contract::load(file)?;
+	find_event(&load_contract(path)?, name_or_signature)
+}
+
+fn find_event(contract: &Contract, name_or_signature: &str) -> anyhow::Result<Event> {
 	let params_start = name_or_signature.find('(');
 
 	match params_start {
@@ -158,6 +352,48 @@ fn load_event(path: &str, name_or_signature: &str) -> anyhow::Result<Event> {
 	}
 }
 
+/// Parses a signature like `transfer(address,uint256)` into its name and param types, optionally
+/// followed by the output types like `balanceOf(address):(uint256)`.
+fn split_signature(signature: &str) -> anyhow::Result<(&str, Vec<ParamType>, Vec<ParamType>)> {
+	let invalid = || anyhow!("invalid signature `{}`", signature);
+	let params_start = signature.find('(').ok_or_else(invalid)?;
+	let name = signature[..params_start].trim();
+	let (inputs, outputs) = match signature[params_start..].split_once("):(") {
+		Some((inputs, outputs)) => (format!("{inputs})"), format!("({outputs}")),
+		None => (signature[params_start..].to_owned(), "()".to_owned()),
+	};
+
+	let kinds = |types: &str| match types.replace(' ', "").as_str() {
+		"()" => Ok(vec![]),
+		types => match Reader::read(types)? {
+			ParamType::Tuple(kinds) => Ok(kinds),
+			_ => Err(invalid()),
+		},
+	};
+
+	Ok((name, kinds(&inputs)?, kinds(&outputs)?))
+}
+
+/// Creates a function with unnamed params from a signature, see [`split_signature`].
+fn parse_signature(signature: &str) -> anyhow::Result<Function> {
+	let (name, inputs, outputs) = split_signature(signature)?;
+	let params = |kinds: Vec<ParamType>| {
+		kinds
+			.into_iter()
+			.map(|kind| Param { name: String::new(), kind, internal_type: None, components: vec![] })
+			.collect()
+	};
+
+	#[allow(deprecated)]
+	Ok(Function {
+		name: name.to_owned(),
+		inputs: params(inputs),
+		outputs: params(outputs),
+		constant: None,
+		state_mutability: StateMutability::NonPayable,
+	})
+}
+
 fn parse_tokens(params: &[(ParamType, &str)], lenient: bool) -> anyhow::Result<Vec<Token>> {
 	params
 		.iter()
@@ -169,19 +405,73 @@ fn parse_tokens(params: &[(ParamType, &str)], lenient: bool) -> anyhow::Result<V
 		.map_err(From::from)
 }
 
-fn encode_input(path: &str, name_or_signature: &str, values: &[String], lenient: bool) -> anyhow::Result<String> {
+/// Input params of a function.
+enum Params {
+	/// Params given on the command line.
+	Args(Vec<String>),
+	/// JSON array of params, or object keyed by param name.
+	Json(Value),
+}
+
+fn encode_input(
+	path: &str,
+	name_or_signature: &str,
+	params: &Params,
+	lenient: bool,
+	format: &Format,
+) -> anyhow::Result<String> {
 	let function = load_function(path, name_or_signature)?;
+	let tokens = input_tokens(&function.inputs, params, lenient)?;
+	let result = function.encode_input(&tokens)?;
 
-	let params: Vec<_> =
-		function.inputs.iter().map(|param| param.kind.clone()).zip(values.iter().map(|v| v as &str)).collect();
+	Ok(encoded(&result, format))
+}
 
-	let tokens = parse_tokens(&params, lenient)?;
-	let result = function.encode_input(&tokens)?;
+fn encode_constructor(
+	path: &str,
+	bytecode_path: Option<&str>,
+	params: &Params,
+	lenient: bool,
+	format: &Format,
+) -> anyhow::Result<String> {
+	let (constructor, bytecode) = load_constructor(path, bytecode_path)?;
+	let tokens = input_tokens(&constructor.inputs, params, lenient)?;
+	let result = constructor.encode_input(bytecode.unwrap_or_default(), &tokens)?;
+
+	Ok(encoded(&result, format))
+}
+
+/// Parses `params` into tokens of the given `inputs`.
+fn input_tokens(inputs: &[Param], params: &Params, lenient: bool) -> anyhow::Result<Vec<Token>> {
+	match params {
+		Params::Args(values) => {
+			if values.len() != inputs.len() {
+				return Err(anyhow!("expected {} params, got {}", inputs.len(), values.len()));
+			}
+			let params: Vec<_> =
+				inputs.iter().map(|param| param.kind.clone()).zip(values.iter().map(|v| v as &str)).collect();
+			parse_tokens(&params, lenient)
+		}
+		Params::Json(value) => Ok(tokens_from_json(inputs, value)?),
+	}
+}
 
-	Ok(hex::encode(result))
+fn encode_params(params: &[String], lenient: bool, keccak: bool, format: &Format) -> anyhow::Result<String> {
+	let (_, tokens) = typed_params(params, lenient)?;
+	let result = encode(&tokens);
+
+	Ok(encoded_or_hash(&result, keccak, format))
+}
+
+fn encode_packed(params: &[String], lenient: bool, keccak: bool, format: &Format) -> anyhow::Result<String> {
+	let (types, tokens) = typed_params(params, lenient)?;
+	let result = ethabi::encode_packed(&types, &tokens)?;
+
+	Ok(encoded_or_hash(&result, keccak, format))
 }
 
-fn encode_params(params: &[String], lenient: bool) -> anyhow::Result<String> {
+/// Parses pairs of types and params given inline.
+fn typed_params(params: &[String], lenient: bool) -> anyhow::Result<(Vec<ParamType>, Vec<Token>)> {
 	assert_eq!(params.len() % 2, 0);
 
 	let params = params
@@ -191,54 +481,402 @@ fn encode_params(params: &[String], lenient: bool) -> anyhow::Result<String> {
 		.collect::<Result<Vec<_>, _>>()?;
 
 	let tokens = parse_tokens(params.as_slice(), lenient)?;
-	let result = encode(&tokens);
+	Ok((params.into_iter().map(|(kind, _)| kind).collect(), tokens))
+}
+
+/// Reads the contents of the file at `path`, or of stdin if the path is `-`.
+fn read_input(path: &str, stdin: &mut dyn Read) -> anyhow::Result<String> {
+	let mut input = String::new();
+	match path {
+		"-" => stdin.read_to_string(&mut input)?,
+		path => File::open(path)?.read_to_string(&mut input)?,
+	};
+	Ok(input)
+}
+
+/// Decodes hex encoded `data`, read from stdin when omitted or `-`.
+fn read_data(data: Option<String>, stdin: &mut dyn Read) -> anyhow::Result<Vec<u8>> {
+	let data = match data {
+		Some(data) if data != "-" => data,
+		_ => read_input("-", stdin)?,
+	};
+	parse_hex(data.trim())
+}
+
+/// Decodes hex encoded `data`, optionally 0x prefixed.
+fn parse_hex(data: &str) -> anyhow::Result<Vec<u8>> {
+	Ok(hex::decode(data.strip_prefix("0x").unwrap_or(data))?)
+}
+
+fn encoded(data: &[u8], format: &Format) -> String {
+	match format.json {
+		true => json!({ "data": format!("0x{}", hex::encode(data)) }).to_string(),
+		false => hex::encode(data),
+	}
+}
 
-	Ok(hex::encode(result))
+/// Formats encoded `data`, or its keccak256 hash if `keccak` is set.
+fn encoded_or_hash(data: &[u8], keccak: bool, format: &Format) -> String {
+	if !keccak {
+		return encoded(data, format);
+	}
+	let hash = hex::encode(Keccak256::digest(data));
+	match format.json {
+		true => json!({ "hash": format!("0x{hash}") }).to_string(),
+		false => hash,
+	}
 }
 
-fn decode_call_output(path: &str, name_or_signature: &str, data: &str) -> anyhow::Result<String> {
+/// Formats decoded params as `type value` lines.
+fn decoded(params: &[Param], tokens: &[Token], format: &Format) -> String {
+	match format.json {
+		true => format.params(params, tokens).to_string(),
+		false => {
+			params.iter().zip(tokens).map(|(param, token)| format!("{} {}", param.kind, format.token(token))).join("\n")
+		}
+	}
+}
+
+fn decode_call_output(path: &str, name_or_signature: &str, data: &[u8], format: &Format) -> anyhow::Result<String> {
 	let function = load_function(path, name_or_signature)?;
-	let data: Vec<u8> = hex::decode(data)?;
-	let tokens = function.decode_output(&data)?;
-	let types = function.outputs;
+	let tokens = function.decode_output(data)?;
 
-	assert_eq!(types.len(), tokens.len());
+	assert_eq!(function.outputs.len(), tokens.len());
 
-	let result =
-		types.iter().zip(tokens.iter()).map(|(ty, to)| format!("{} {to}", ty.kind)).collect::<Vec<String>>().join("\n");
+	Ok(decoded(&function.outputs, &tokens, format))
+}
 
-	Ok(result)
+fn decode_calldata(path_or_signature: &str, data: &[u8], format: &Format) -> anyhow::Result<String> {
+	let functions = match path_or_signature.contains('(') {
+		true => vec![parse_signature(path_or_signature)?],
+		false => load_contract(path_or_signature)?.functions().cloned().collect(),
+	};
+	decode_calldata_with(&functions, data, format)
 }
 
-fn decode_params(types: &[String], data: &str) -> anyhow::Result<String> {
-	let types: Vec<ParamType> = types.iter().map(|s| Reader::read(s)).collect::<Result<_, _>>()?;
+/// Decodes function call input with the function of `functions` matching its selector.
+fn decode_calldata_with(functions: &[Function], data: &[u8], format: &Format) -> anyhow::Result<String> {
+	if data.len() < 4 {
+		return Err(anyhow!("calldata is shorter than a function selector"));
+	}
 
-	let data: Vec<u8> = hex::decode(data)?;
+	let (selector, arguments) = data.split_at(4);
+	// selectors may collide, pick the first function the arguments decode with
+	let (function, tokens) = functions
+		.iter()
+		.filter(|function| function.short_signature() == selector)
+		.find_map(|function| Some((function, function.decode_input(arguments).ok()?)))
+		.ok_or_else(|| anyhow!("no function with selector `{}` decodes the calldata", hex::encode(selector)))?;
 
-	let tokens = decode(&types, &data)?;
+	let signature = format!("{}({})", function.name, function.inputs.iter().map(|param| &param.kind).join(","));
+	if format.json {
+		return Ok(json!({ "function": signature, "inputs": format.params(&function.inputs, &tokens) }).to_string());
+	}
+
+	Ok(std::iter::once(signature).chain(named(&function.inputs, &tokens, format)).join("\n"))
+}
+
+/// Formats decoded params as `name value` lines, falling back to the type of unnamed params.
+fn named(params: &[Param], tokens: &[Token], format: &Format) -> Vec<String> {
+	params
+		.iter()
+		.zip(tokens)
+		.map(|(param, token)| match param.name.is_empty() {
+			true => format!("{} {}", param.kind, format.token(token)),
+			false => format!("{} {}", param.name, format.token(token)),
+		})
+		.collect()
+}
+
+fn decode_params(types: &[String], data: &[u8], format: &Format) -> anyhow::Result<String> {
+	let types: Vec<ParamType> = types.iter().map(|s| Reader::read(s)).collect::<Result<_, _>>()?;
+
+	let tokens = decode(&types, data)?;
 
 	assert_eq!(types.len(), tokens.len());
 
-	let result =
-		types.iter().zip(tokens.iter()).map(|(ty, to)| format!("{ty} {to}")).collect::<Vec<String>>().join("\n");
+	let params: Vec<_> = types
+		.into_iter()
+		.map(|kind| Param { name: String::new(), kind, internal_type: None, components: vec![] })
+		.collect();
 
-	Ok(result)
+	Ok(decoded(&params, &tokens, format))
 }
 
-fn decode_log(path: &str, name_or_signature: &str, topics: &[String], data: &str) -> anyhow::Result<String> {
+fn decode_log(
+	path: &str,
+	name_or_signature: &str,
+	topics: &[String],
+	data: &[u8],
+	format: &Format,
+) -> anyhow::Result<String> {
 	let event = load_event(path, name_or_signature)?;
 	let topics: Vec<Hash> = topics.iter().map(|t| t.parse()).collect::<Result<_, _>>()?;
-	let data = hex::decode(data)?;
-	let decoded = event.parse_log((topics, data).into())?;
+	let decoded = event.parse_log((topics, data.to_vec()).into())?;
 
-	let result = decoded
+	Ok(decoded_log(&event, decoded, format))
+}
+
+fn decoded_log(event: &Event, decoded: Log, format: &Format) -> String {
+	if format.json {
+		// log params are in the order of the event inputs
+		let params: Vec<_> = event
+			.inputs
+			.iter()
+			.zip(&decoded.params)
+			.map(|(input, log_param)| format.param(&log_param.name, &input.kind, &input.components, &log_param.value))
+			.collect();
+		return json!({ "event": event.name, "params": params }).to_string();
+	}
+
+	decoded
 		.params
 		.into_iter()
-		.map(|log_param| format!("{} {}", log_param.name, log_param.value))
+		.map(|log_param| format!("{} {}", log_param.name, format.token(&log_param.value)))
 		.collect::<Vec<String>>()
-		.join("\n");
+		.join("\n")
+}
+
+fn decode_constructor(path: &str, bytecode_path: Option<&str>, data: &[u8], format: &Format) -> anyhow::Result<String> {
+	let (constructor, bytecode) = load_constructor(path, bytecode_path)?;
+	let tokens = match bytecode {
+		Some(bytecode) => constructor
+			.decode_input(bytecode, data)
+			.map_err(|err| anyhow!("cannot decode the arguments following the creation bytecode: {err}"))?,
+		None => constructor.decode_input_suffix(data)?,
+	};
+
+	Ok(decoded(&constructor.inputs, &tokens, format))
+}
+
+fn decode_revert(path: Option<&str>, data: &[u8], format: &Format) -> anyhow::Result<String> {
+	let revert = match path {
+		Some(path) => Revert::decode_with_errors(data, load_contract(path)?.errors()),
+		None => Revert::decode(data),
+	};
+	Ok(decoded_revert(&revert, format))
+}
+
+/// Formats a decoded revert as its error signature followed by `name value` lines.
+fn decoded_revert(revert: &Revert, format: &Format) -> String {
+	let (name, params, tokens) = match *revert {
+		Revert::Error(ref reason) => {
+			("Error", vec![Param::new("reason", ParamType::String)], vec![Token::String(reason.clone())])
+		}
+		Revert::Panic(code) => ("Panic", vec![Param::new("code", ParamType::Uint(256))], vec![Token::Uint(code)]),
+		Revert::Custom(ref error, ref tokens) => (error.name.as_str(), error.inputs.clone(), tokens.clone()),
+		Revert::Unknown(ref data) => {
+			let selector = data.get(..4).map(hex::encode);
+			if format.json {
+				let selector = selector.map(|selector| format!("0x{selector}"));
+				return json!({ "selector": selector, "data": format!("0x{}", hex::encode(data)) }).to_string();
+			}
+			return match selector {
+				Some(selector) if data.len() == 4 => format!("unknown error 0x{selector}"),
+				Some(selector) => format!("unknown error 0x{selector}\ndata {}", hex::encode(&data[4..])),
+				None if data.is_empty() => "revert without data".to_owned(),
+				None => format!("invalid revert data 0x{}", hex::encode(data)),
+			};
+		}
+	};
+
+	let kinds: Vec<_> = params.iter().map(|param| param.kind.clone()).collect();
+	let signature = canonical(name, &kinds);
+	let reason = revert.panic_reason();
+	if format.json {
+		let mut result = json!({ "error": signature, "params": format.params(&params, &tokens) });
+		if let Some(reason) = reason {
+			result["reason"] = reason.into();
+		}
+		return result.to_string();
+	}
+
+	std::iter::once(signature)
+		.chain(named(&params, &tokens, format))
+		.chain(reason.map(|r| format!("reason {r}")))
+		.join("\n")
+}
+
+fn decode_receipt(abi_paths: &[String], receipt: &str, format: &Format) -> anyhow::Result<String> {
+	let mut decoder = LogDecoder::new();
+	for abi_path in abi_paths {
+		// `@` only separates an address, paths like `node_modules/@openzeppelin/...` are kept whole
+		let split = abi_path.rsplit_once('@').and_then(|(path, address)| {
+			let address = address.strip_prefix("0x").unwrap_or(address);
+			let address = (address.len() == 40).then(|| address.parse::<Address>().ok()).flatten()?;
+			Some((path, address))
+		});
+		let (path, address) = match split {
+			Some((path, address)) => (path, Some(address)),
+			None => (abi_path.as_str(), None),
+		};
+		let artifact = load_artifact(path)?;
+		let name = match artifact.contract_name {
+			Some(ref name) => name.clone(),
+			None => Path::new(path).file_stem().map(|stem| stem.to_string_lossy().into_owned()).unwrap_or_default(),
+		};
+		match address {
+			Some(address) => decoder.register(address, &name, &artifact.abi),
+			None => decoder.register_any(&name, &artifact.abi),
+		}
+	}
+
+	let receipt: Value = serde_json::from_str(receipt)?;
+	let receipt = receipt.get("result").unwrap_or(&receipt);
+	let logs = receipt.get("logs").and_then(Value::as_array).ok_or_else(|| anyhow!("receipt has no `logs` array"))?;
+	let entries = logs.iter().map(log_entry).collect::<anyhow::Result<Vec<_>>>()?;
+
+	let mut lines = Vec::new();
+	let mut json_logs = Vec::new();
+	for (index, entry) in entries.iter().enumerate() {
+		let address = format.address(&entry.address);
+		// undecodable logs are reported, not failed on
+		let error = match (decoder.decode(entry), entry.log.topics.first()) {
+			(Ok(Some(decoded)), _) => {
+				let event = decoded.event;
+				let params = &decoded.log.params;
+				match format.json {
+					true => {
+						let params: Vec<_> = event
+							.inputs
+							.iter()
+							.zip(params)
+							.map(|(input, param)| {
+								format.param(&param.name, &input.kind, &input.components, &param.value)
+							})
+							.collect();
+						json_logs.push(json!({
+							"index": index,
+							"address": address,
+							"contract": decoded.contract,
+							"event": event.name,
+							"params": params,
+						}));
+					}
+					false => {
+						lines.push(format!("log {index}: {} ({}) at {address}", event.name, decoded.contract));
+						lines.extend(
+							params.iter().map(|param| format!("{} {}", param.name, format.token(&param.value))),
+						);
+					}
+				}
+				continue;
+			}
+			(Ok(None), Some(topic0)) => format!("unknown event {topic0:?}"),
+			(Ok(None), None) => "log without topics".to_owned(),
+			(Err(err), Some(topic0)) => format!("cannot decode event {topic0:?}: {err}"),
+			(Err(err), None) => err.to_string(),
+		};
+		match format.json {
+			true => json_logs.push(json!({ "index": index, "address": address, "error": error })),
+			false => lines.push(format!("log {index}: {error} at {address}")),
+		}
+	}
+
+	match format.json {
+		true => Ok(Value::Array(json_logs).to_string()),
+		false => Ok(lines.join("\n")),
+	}
+}
+
+/// Parses a log object of a JSON-RPC receipt.
+fn log_entry(log: &Value) -> anyhow::Result<LogEntry> {
+	let field = |name: &str| log.get(name).ok_or_else(|| anyhow!("receipt log has no `{}` field", name));
+	let hex_field = |name: &str| {
+		let value = field(name)?.as_str().ok_or_else(|| anyhow!("invalid `{}` of receipt log", name))?;
+		Ok::<_, anyhow::Error>(value.trim_start_matches("0x"))
+	};
+
+	let address = hex_field("address")?.parse()?;
+	let topics = field("topics")?
+		.as_array()
+		.ok_or_else(|| anyhow!("invalid `topics` of receipt log"))?
+		.iter()
+		.map(|topic| {
+			let topic = topic.as_str().ok_or_else(|| anyhow!("invalid `topics` of receipt log"))?;
+			Ok(topic.trim_start_matches("0x").parse()?)
+		})
+		.collect::<anyhow::Result<_>>()?;
+	let data = hex::decode(hex_field("data")?)?;
+
+	Ok((address, RawLog { topics, data }).into())
+}
+
+fn selector(signature: &str, format: &Format) -> anyhow::Result<String> {
+	let (name, kinds, _) = split_signature(signature)?;
+	let selector = hex::encode(short_signature(name, &kinds));
+	match format.json {
+		true => Ok(json!({ "signature": canonical(name, &kinds), "selector": format!("0x{selector}") }).to_string()),
+		false => Ok(selector),
+	}
+}
+
+fn topic(signature: &str, format: &Format) -> anyhow::Result<String> {
+	let (name, kinds, _) = split_signature(signature)?;
+	let topic = hex::encode(long_signature(name, &kinds));
+	match format.json {
+		true => Ok(json!({ "signature": canonical(name, &kinds), "topic": format!("0x{topic}") }).to_string()),
+		false => Ok(topic),
+	}
+}
 
-	Ok(result)
+fn selectors(path: &str, format: &Format) -> anyhow::Result<String> {
+	Ok(contract_selectors(&load_contract(path)?, format, |_, _| true))
+}
+
+/// Lists the selectors and topics of the items of `contract` for which `filter` returns true given
+/// their type and canonical signature.
+fn contract_selectors<F>(contract: &Contract, format: &Format, filter: F) -> String
+where
+	F: Fn(&str, &str) -> bool,
+{
+	let functions = contract.functions().map(|function| {
+		let kinds: Vec<_> = function.inputs.iter().map(|param| param.kind.clone()).collect();
+		(hex::encode(function.short_signature()), "function", canonical(&function.name, &kinds), false)
+	});
+	let events = contract.events().map(|event| {
+		let kinds: Vec<_> = event.inputs.iter().map(|param| param.kind.clone()).collect();
+		(hex::encode(event.signature()), "event", canonical(&event.name, &kinds), event.anonymous)
+	});
+	let errors = contract.errors().map(|error| {
+		let kinds: Vec<_> = error.inputs.iter().map(|param| param.kind.clone()).collect();
+		(hex::encode(short_signature(&error.name, &kinds)), "error", canonical(&error.name, &kinds), false)
+	});
+	let items = functions.chain(events).chain(errors).filter(|(_, kind, signature, _)| filter(kind, signature));
+
+	if format.json {
+		let items: Vec<_> = items
+			.map(|(selector, kind, signature, anonymous)| {
+				json!({ "type": kind, "signature": signature, "selector": format!("0x{selector}"), "anonymous": anonymous })
+			})
+			.collect();
+		return Value::Array(items).to_string();
+	}
+
+	// anonymous events do not emit their topic, but it is listed for completeness
+	items
+		.map(|(selector, kind, signature, anonymous)| match anonymous {
+			true => format!("{selector} {kind} {signature} anonymous"),
+			false => format!("{selector} {kind} {signature}"),
+		})
+		.join("\n")
+}
+
+fn canonical(name: &str, kinds: &[ParamType]) -> String {
+	format!("{name}({})", kinds.iter().join(","))
+}
+
+/// Generates formatted bindings for the contract at `path`.
+fn codegen(path: &str) -> anyhow::Result<String> {
+	let contract = load_contract(path)?;
+	let bindings = ethabi_codegen::Contract::try_from(&contract)?.generate();
+	// lints are not reported for the macro expansion of `use_contract!`, the module allows the same
+	let file: syn::File = syn::parse_str(&format!(
+		"#![allow(clippy::all, deprecated, dead_code, missing_docs, unused_imports, unused_mut, unused_variables)] {bindings}"
+	))?;
+	let name = Path::new(path).file_name().map_or(path.into(), |name| name.to_string_lossy());
+
+	Ok(format!("// Generated by `ethabi codegen` from `{name}`, do not edit.\n\n{}", prettyplease::unparse(&file)))
 }
 
 fn hash_signature(sig: &str) -> Hash {
@@ -247,7 +885,8 @@ fn hash_signature(sig: &str) -> Hash {
 
 #[cfg(test)]
 mod tests {
-	use super::execute;
+	use super::{execute, execute_with_stdin};
+	use std::fs;
 
 	#[test]
 	fn simple_encode() {
@@ -347,6 +986,16 @@ mod tests {
 		assert_eq!(execute(command).unwrap(), expected);
 	}
 
+	#[test]
+	fn function_encode_from_artifact() {
+		let command = "ethabi encode function ../res/Greeter.json setGreeting -p hi".split(' ');
+		let expected = "a4136862\
+		                0000000000000000000000000000000000000000000000000000000000000020\
+		                0000000000000000000000000000000000000000000000000000000000000002\
+		                6869000000000000000000000000000000000000000000000000000000000000";
+		assert_eq!(execute(command).unwrap(), expected);
+	}
+
 	#[test]
 	fn nonexistent_function() {
 		// This should fail because there is no function called 'nope' in the ABI
@@ -438,4 +1087,339 @@ b 4444444444444444444444444444444444444444";
 		let command = "ethabi decode log ../res/event.abi Nope(bool,address) -l 0000000000000000000000000000000000000000000000000000000000000000 0000000000000000000000004444444444444444444444444444444444444444".split(' ');
 		assert!(execute(command).is_err());
 	}
+
+	#[test]
+	fn calldata_decode() {
+		let command = "ethabi decode calldata ../res/eip20.abi 0xa9059cbb000000000000000000000000111111111111111111111111111111111111111100000000000000000000000000000000000000000000000000000000000003e8".split(' ');
+		let expected = "transfer(address,uint256)
+_to 1111111111111111111111111111111111111111
+_value 3e8";
+		assert_eq!(execute(command).unwrap(), expected);
+	}
+
+	#[test]
+	fn calldata_decode_overloaded() {
+		let command = "ethabi decode calldata ../res/test.abi d473a8ed000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000013100000000000000000000000000000000000000000000000000000000000000".split(' ');
+		let expected = "bar(string)
+a 1";
+		assert_eq!(execute(command).unwrap(), expected);
+	}
+
+	#[test]
+	fn calldata_decode_signature() {
+		let command = "ethabi decode calldata transfer(address,uint256) a9059cbb000000000000000000000000111111111111111111111111111111111111111100000000000000000000000000000000000000000000000000000000000003e8".split(' ');
+		let expected = "transfer(address,uint256)
+address 1111111111111111111111111111111111111111
+uint256 3e8";
+		assert_eq!(execute(command).unwrap(), expected);
+
+		let command = "ethabi decode calldata approve(address,uint256) a9059cbb000000000000000000000000111111111111111111111111111111111111111100000000000000000000000000000000000000000000000000000000000003e8".split(' ');
+		assert!(execute(command).is_err());
+	}
+
+	#[test]
+	fn selector() {
+		let command = vec!["ethabi", "selector", "transfer(address, uint256)"];
+		assert_eq!(execute(command).unwrap(), "a9059cbb");
+
+		let command = vec!["ethabi", "selector", "aggregate3((address,bool,bytes)[])"];
+		assert_eq!(execute(command).unwrap(), "82ad56cb");
+
+		let command = vec!["ethabi", "selector", "transfer"];
+		assert!(execute(command).is_err());
+	}
+
+	#[test]
+	fn topic() {
+		let command = vec!["ethabi", "topic", "Transfer(address,address,uint256)"];
+		let expected = "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+		assert_eq!(execute(command).unwrap(), expected);
+	}
+
+	#[test]
+	fn selectors() {
+		let command = "ethabi selectors ../res/test.abi".split(' ');
+		let expected = "6fae9412 function bar(bool)
+d473a8ed function bar(string)
+45557578 function foo(bool)";
+		assert_eq!(execute(command).unwrap(), expected);
+
+		let command = "ethabi selectors ../res/event.abi".split(' ');
+		let expected =
+			"e96bdb08c10cfe7a5696e60723d445d4b47f319cac1dab0ed62c9e358f440877 event Event(bool,address) anonymous";
+		assert_eq!(execute(command).unwrap(), expected);
+	}
+
+	#[test]
+	fn json_output() {
+		let command = "ethabi encode params -v bool 1 --json".split(' ');
+		let expected = r#"{"data":"0x0000000000000000000000000000000000000000000000000000000000000001"}"#;
+		assert_eq!(execute(command).unwrap(), expected);
+
+		let command = "ethabi decode calldata ../res/eip20.abi a9059cbb000000000000000000000000111111111111111111111111111111111111111100000000000000000000000000000000000000000000000000000000000003e8 --json".split(' ');
+		let expected = r#"{"function":"transfer(address,uint256)","inputs":[{"name":"_to","type":"address","value":"0x1111111111111111111111111111111111111111"},{"name":"_value","type":"uint256","value":"0x3e8"}]}"#;
+		assert_eq!(execute(command).unwrap(), expected);
+
+		let command = "ethabi decode log ../res/event.abi Event -l 0000000000000000000000000000000000000000000000000000000000000001 0000000000000000000000004444444444444444444444444444444444444444 --json".split(' ');
+		let expected = r#"{"event":"Event","params":[{"name":"a","type":"bool","value":true},{"name":"b","type":"address","value":"0x4444444444444444444444444444444444444444"}]}"#;
+		assert_eq!(execute(command).unwrap(), expected);
+	}
+
+	#[test]
+	fn json_output_event_tuple() {
+		let command = "ethabi --json decode log ../res/tuple_event.abi Placed \
+			-l c6f32ae44cf7afd6689c644560e37bdd0925f2267c45fb1b1faa8d83a38556dc \
+			-l 0000000000000000000000001111111111111111111111111111111111111111 \
+			00000000000000000000000000000000000000000000000000000000000000070000000000000000000000000000000000000000000000000000000000000001";
+		let expected = r#"{"event":"Placed","params":[{"name":"maker","type":"address","value":"0x1111111111111111111111111111111111111111"},{"name":"order","type":"(uint8,bool)","value":[{"name":"size","type":"uint8","value":"0x7"},{"name":"open","type":"bool","value":true}]}]}"#;
+		assert_eq!(execute(command.split_whitespace()).unwrap(), expected);
+	}
+
+	#[test]
+	fn json_output_nested() {
+		let command = "ethabi --json decode params -t (uint8,bool)[] 0000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000070000000000000000000000000000000000000000000000000000000000000001".split(' ');
+		let expected = r#"[{"name":"","type":"(uint8,bool)[]","value":[[{"name":"","type":"uint8","value":"0x7"},{"name":"","type":"bool","value":true}]]}]"#;
+		assert_eq!(execute(command).unwrap(), expected);
+	}
+
+	#[test]
+	fn format_output() {
+		let command = "ethabi decode params -t int256 -t address fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe0000000000000000000000005aaeb6053f3e94c9b9a09f33669435e7ef1beaed --format decimal,checksum".split(' ');
+		let expected = "int256 -2
+address 0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+		assert_eq!(execute(command).unwrap(), expected);
+
+		let command = "ethabi decode params -t uint256[] 000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000010 --format decimal --json".split(' ');
+		let expected = r#"[{"name":"","type":"uint256[]","value":["16"]}]"#;
+		assert_eq!(execute(command).unwrap(), expected);
+	}
+
+	#[test]
+	fn function_encode_from_json() {
+		let expected = "455575780000000000000000000000000000000000000000000000000000000000000001";
+
+		let command = "ethabi encode function ../res/test.abi foo -f -".split(' ');
+		assert_eq!(execute_with_stdin(command, &mut r#"{ "a": true }"#.as_bytes()).unwrap(), expected);
+
+		let command = "ethabi encode function ../res/test.abi foo --params-file -".split(' ');
+		assert_eq!(execute_with_stdin(command, &mut "[true]".as_bytes()).unwrap(), expected);
+
+		let command = "ethabi encode function ../res/test.abi foo -f -".split(' ');
+		assert!(execute_with_stdin(command, &mut r#"{ "b": true }"#.as_bytes()).is_err());
+	}
+
+	#[test]
+	fn decode_from_stdin() {
+		let command = "ethabi decode params -t bool".split(' ');
+		let stdin = "0x0000000000000000000000000000000000000000000000000000000000000001\n";
+		assert_eq!(execute_with_stdin(command, &mut stdin.as_bytes()).unwrap(), "bool true");
+
+		let command = "ethabi decode calldata ../res/test.abi -".split(' ');
+		let stdin = "455575780000000000000000000000000000000000000000000000000000000000000001";
+		assert_eq!(execute_with_stdin(command, &mut stdin.as_bytes()).unwrap(), "foo(bool)\na true");
+	}
+
+	#[test]
+	fn receipt_decode() {
+		let command = "ethabi decode receipt ../res/eip20.abi ../res/event.abi ../res/receipt.json".split(' ');
+		let expected = "log 0: Transfer (eip20) at 0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa
+from 1111111111111111111111111111111111111111
+to 2222222222222222222222222222222222222222
+value 3e8
+log 1: Approval (eip20) at 0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa
+owner 1111111111111111111111111111111111111111
+spender 3333333333333333333333333333333333333333
+value 5
+log 2: unknown event 0x9999999999999999999999999999999999999999999999999999999999999999 at 0xbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb
+log 3: cannot decode event 0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef: Invalid data at 0xcccccccccccccccccccccccccccccccccccccccc";
+		assert_eq!(execute(command).unwrap(), expected);
+	}
+
+	#[test]
+	fn receipt_decode_by_address() {
+		let command =
+			"ethabi decode receipt ../res/eip20.abi@0xcccccccccccccccccccccccccccccccccccccccc - --json".split(' ');
+		let receipt = include_str!("../../res/receipt.json");
+		let result: serde_json::Value =
+			serde_json::from_str(&execute_with_stdin(command, &mut receipt.as_bytes()).unwrap()).unwrap();
+
+		assert_eq!(result.as_array().unwrap().len(), 4);
+		assert_eq!(
+			result[0],
+			serde_json::json!({
+				"index": 0,
+				"address": "0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+				"error": "unknown event 0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+			})
+		);
+		assert_eq!(
+			result[3]["error"],
+			"cannot decode event 0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef: Invalid data"
+		);
+	}
+
+	#[test]
+	fn receipt_decode_scoped_package_path() {
+		let dir = std::env::temp_dir().join(format!("ethabi_receipt_{}", std::process::id())).join("@oz");
+		fs::create_dir_all(&dir).unwrap();
+		let abi = dir.join("ERC20.abi");
+		fs::copy("../res/eip20.abi", &abi).unwrap();
+
+		let command = vec!["ethabi", "decode", "receipt", abi.to_str().unwrap(), "../res/receipt.json"];
+		let output = execute(command).unwrap();
+		assert!(
+			output.starts_with("log 0: Transfer (ERC20) at 0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"),
+			"{output}"
+		);
+
+		let path = format!("{}@0xcccccccccccccccccccccccccccccccccccccccc", abi.display());
+		let command = vec!["ethabi", "decode", "receipt", &path, "../res/receipt.json"];
+		assert!(execute(command).unwrap().starts_with("log 0: unknown event"));
+
+		fs::remove_dir_all(dir.parent().unwrap()).unwrap();
+	}
+
+	#[test]
+	fn encode_packed() {
+		let command =
+			"ethabi encode packed -v string hello -v bytes4 0xa9059cbb -v bool true -v int16 -2 -l".split(' ');
+		assert_eq!(execute(command).unwrap(), "68656c6c6fa9059cbb01fffe");
+
+		let command = "ethabi encode packed -v uint8 300 -l".split(' ');
+		assert!(execute(command).is_err());
+	}
+
+	#[test]
+	fn encode_keccak() {
+		let command = "ethabi encode packed -v string hello --keccak".split(' ');
+		assert_eq!(execute(command).unwrap(), "1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8");
+
+		let command = "ethabi encode params -v string hello --keccak --json".split(' ');
+		let expected = r#"{"hash":"0x984002fcc0ca639f96622add24c2edd2fe72c65e71ca3faa243e091e0bc7cdab"}"#;
+		assert_eq!(execute(command).unwrap(), expected);
+	}
+
+	#[test]
+	fn revert_decode_builtin() {
+		let command = "ethabi decode revert 0x08c379a0000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000046e6f706500000000000000000000000000000000000000000000000000000000".split(' ');
+		assert_eq!(execute(command).unwrap(), "Error(string)\nreason nope");
+
+		let command =
+			"ethabi decode revert 4e487b710000000000000000000000000000000000000000000000000000000000000011".split(' ');
+		assert_eq!(execute(command).unwrap(), "Panic(uint256)\ncode 11\nreason arithmetic overflow or underflow");
+
+		let command =
+			"ethabi --json decode revert 4e487b710000000000000000000000000000000000000000000000000000000000000001"
+				.split(' ');
+		let expected = r#"{"error":"Panic(uint256)","params":[{"name":"code","type":"uint256","value":"0x1"}],"reason":"assertion failed"}"#;
+		assert_eq!(execute(command).unwrap(), expected);
+	}
+
+	#[test]
+	fn revert_decode_custom() {
+		let data = "cf47918100000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000000000000000000000000000000000096";
+
+		let command = vec!["ethabi", "decode", "revert", "--abi", "../res/errors.abi", data, "--format", "decimal"];
+		assert_eq!(execute(command).unwrap(), "InsufficientBalance(uint256,uint256)\navailable 100\nrequired 150");
+
+		let expected = "InsufficientBalance(uint256,uint256)\navailable 64\nrequired 96";
+		let command = "ethabi decode revert --abi ../res/errors.abi -".split(' ');
+		assert_eq!(execute_with_stdin(command, &mut data.as_bytes()).unwrap(), expected);
+		let command = "ethabi decode revert --abi ../res/errors.abi".split(' ');
+		assert_eq!(execute_with_stdin(command, &mut data.as_bytes()).unwrap(), expected);
+	}
+
+	#[test]
+	fn revert_decode_unknown() {
+		let command =
+			"ethabi decode revert cf479181000000000000000000000000000000000000000000000000000000000000002a".split(' ');
+		let expected =
+			"unknown error 0xcf479181\ndata 000000000000000000000000000000000000000000000000000000000000002a";
+		assert_eq!(execute(command).unwrap(), expected);
+
+		let command = "ethabi --json decode revert 0xcf479181".split(' ');
+		assert_eq!(execute(command).unwrap(), r#"{"data":"0xcf479181","selector":"0xcf479181"}"#);
+
+		let command = vec!["ethabi", "decode", "revert", ""];
+		assert_eq!(execute(command).unwrap(), "revert without data");
+	}
+
+	#[test]
+	fn codegen_bindings() {
+		let command = "ethabi codegen ../res/eip20.abi".split(' ');
+		let bindings = execute(command).unwrap();
+		// the bindings checked into the tests crate are regenerated with the same command
+		assert_eq!(bindings, include_str!("../../tests/src/eip20_bindings.rs"));
+
+		let output = std::env::temp_dir().join(format!("ethabi_codegen_bindings_{}.rs", std::process::id()));
+		let command = vec!["ethabi", "codegen", "../res/eip20.abi", "-o", output.to_str().unwrap()];
+		assert_eq!(execute(command).unwrap(), "");
+		assert_eq!(fs::read_to_string(&output).unwrap(), bindings);
+		fs::remove_file(output).unwrap();
+	}
+
+	#[test]
+	fn codegen_tuples_unsupported() {
+		let command = "ethabi codegen ../res/Multicall3.abi".split(' ');
+		let err = execute(command).unwrap_err();
+		assert!(err.to_string().contains("has tuple params, which are not supported"), "{err}");
+	}
+
+	#[test]
+	fn constructor_encode_from_artifact() {
+		let command = "ethabi encode constructor ../res/Greeter.json -p hi".split(' ');
+		let expected = "608060405234801561001057600080fd5b5060405161001d90610036565b604051809103906000f080158015610037573d6000803e3d6000fd5b50000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000026869000000000000000000000000000000000000000000000000000000000000";
+		assert_eq!(execute(command).unwrap(), expected);
+
+		let command = "ethabi decode constructor ../res/Greeter.json -".split(' ');
+		assert_eq!(execute_with_stdin(command, &mut expected.as_bytes()).unwrap(), "string hi");
+	}
+
+	#[test]
+	fn constructor_encode_without_bytecode() {
+		let command =
+			"ethabi encode constructor ../res/constructor.abi -p 1111111111111111111111111111111111111111".split(' ');
+		let expected = "0000000000000000000000001111111111111111111111111111111111111111";
+		assert_eq!(execute(command).unwrap(), expected);
+
+		let command = "ethabi encode constructor ../res/constructor.abi -f -".split(' ');
+		let params = r#"{ "a": "0x1111111111111111111111111111111111111111" }"#;
+		assert_eq!(execute_with_stdin(command, &mut params.as_bytes()).unwrap(), expected);
+	}
+
+	#[test]
+	fn constructor_encode_missing_params() {
+		let command = "ethabi encode constructor ../res/constructor.abi".split(' ');
+		assert_eq!(execute(command).unwrap_err().to_string(), "expected 1 params, got 0");
+	}
+
+	#[test]
+	fn constructor_decode_from_artifact() {
+		let command = "ethabi decode constructor ../res/Greeter.json 608060405234801561001057600080fd5b5060405161001d90610036565b604051809103906000f080158015610037573d6000803e3d6000fd5b50000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000026869000000000000000000000000000000000000000000000000000000000000".split(' ');
+		let expected = "string hi";
+		assert_eq!(execute(command).unwrap(), expected);
+	}
+
+	#[test]
+	fn constructor_decode_with_linked_library() {
+		// the library address replaces the placeholder of the artifact bytecode
+		let command = "ethabi decode constructor ../res/Linked.json 6000222222222222222222222222222222222222222260010000000000000000000000001111111111111111111111111111111111111111".split(' ');
+		assert_eq!(execute(command).unwrap(), "address 1111111111111111111111111111111111111111");
+
+		let command = "ethabi decode constructor ../res/Linked.json 6000222222222222222222222222222222222222222260020000000000000000000000001111111111111111111111111111111111111111".split(' ');
+		assert!(execute(command).is_err());
+	}
+
+	#[test]
+	fn constructor_decode_without_bytecode() {
+		let command = "ethabi decode constructor ../res/constructor.abi 6080604052000000000000000000000000111111111111111111111111111111111111111111".split(' ');
+		let expected = "address 1111111111111111111111111111111111111111";
+		assert_eq!(execute(command).unwrap(), expected);
+	}
+
+	#[test]
+	fn constructor_decode_bytecode_mismatch() {
+		let command = "ethabi decode constructor ../res/Greeter.json 60806040520000000000000000000000000000000000000000000000000000000000000020".split(' ');
+		assert!(execute(command).is_err());
+	}
 }
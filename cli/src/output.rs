@@ -0,0 +1,147 @@
+//! Formatting of command output.
+
+use ethabi::{Address, Param, ParamType, Token, TupleParam, Uint};
+use itertools::Itertools;
+use serde_json::{json, Value};
+use sha3::{Digest, Keccak256};
+
+/// Output options shared by all commands.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Format {
+	/// Emit structured JSON instead of text lines.
+	pub json: bool,
+	/// Print integers in decimal instead of hex.
+	pub decimal: bool,
+	/// Print addresses with their EIP-55 checksum.
+	pub checksum: bool,
+}
+
+impl Format {
+	/// Creates the output format from the `--json` flag and the `--format` values.
+	pub fn new(json: bool, options: &[String]) -> Self {
+		let mut format = Format { json, ..Default::default() };
+		for option in options {
+			match option.as_str() {
+				"decimal" => format.decimal = true,
+				"hex" => format.decimal = false,
+				"checksum" => format.checksum = true,
+				_ => {}
+			}
+		}
+		format
+	}
+
+	/// Formats `token` like its `Display` implementation does, applying the format to nested values.
+	pub fn token(&self, token: &Token) -> String {
+		match *token {
+			Token::Address(ref address) if self.checksum => checksum(address),
+			Token::Uint(ref value) if self.decimal => value.to_string(),
+			Token::Int(ref value) if self.decimal => signed(value),
+			Token::Array(ref tokens) | Token::FixedArray(ref tokens) => {
+				format!("[{}]", tokens.iter().map(|token| self.token(token)).join(","))
+			}
+			Token::Tuple(ref tokens) => format!("({})", tokens.iter().map(|token| self.token(token)).join(",")),
+			ref token => token.to_string(),
+		}
+	}
+
+	/// Formats a 0x prefixed address.
+	pub fn address(&self, address: &Address) -> String {
+		match self.checksum {
+			true => checksum(address),
+			false => format!("{address:?}"),
+		}
+	}
+
+	/// Formats a list of `params` and their decoded `tokens` as JSON objects with the name, type and
+	/// value of each param.
+	pub fn params(&self, params: &[Param], tokens: &[Token]) -> Value {
+		Value::Array(
+			params
+				.iter()
+				.zip(tokens)
+				.map(|(param, token)| self.param(&param.name, &param.kind, &param.components, token))
+				.collect(),
+		)
+	}
+
+	/// Formats a single param, `components` describing the tuple of a tuple or tuple array param.
+	pub fn param(&self, name: &str, kind: &ParamType, components: &[TupleParam], token: &Token) -> Value {
+		json!({ "name": name, "type": kind.to_string(), "value": self.value(kind, components, token) })
+	}
+
+	fn value(&self, kind: &ParamType, components: &[TupleParam], token: &Token) -> Value {
+		match (kind, token) {
+			(ParamType::Tuple(kinds), Token::Tuple(tokens)) => Value::Array(
+				kinds
+					.iter()
+					.zip(tokens)
+					.enumerate()
+					.map(|(i, (kind, token))| {
+						let component = components.get(i);
+						let name = component.and_then(|c| c.name.as_deref()).unwrap_or_default();
+						self.param(name, kind, component.map_or(&[], |c| &c.components), token)
+					})
+					.collect(),
+			),
+			(ParamType::Array(kind), Token::Array(tokens))
+			| (ParamType::FixedArray(kind, _), Token::FixedArray(tokens)) => {
+				Value::Array(tokens.iter().map(|token| self.value(kind, components, token)).collect())
+			}
+			(_, Token::Address(address)) => self.address(address).into(),
+			(_, Token::Uint(value)) if self.decimal => value.to_string().into(),
+			(_, Token::Int(value)) if self.decimal => signed(value).into(),
+			(_, Token::Uint(value)) | (_, Token::Int(value)) => format!("{value:#x}").into(),
+			(_, Token::Bool(value)) => (*value).into(),
+			(_, Token::String(value)) => value.clone().into(),
+			(_, Token::Bytes(bytes)) | (_, Token::FixedBytes(bytes)) => format!("0x{}", hex::encode(bytes)).into(),
+			(_, Token::HashedTopic(hash)) => format!("{hash:?}").into(),
+			(_, token) => token.to_string().into(),
+		}
+	}
+}
+
+/// Formats a two's complement integer in signed decimal.
+fn signed(value: &Uint) -> String {
+	match value.bit(255) {
+		true => format!("-{}", (!*value).overflowing_add(Uint::one()).0),
+		false => value.to_string(),
+	}
+}
+
+/// Formats an address with its EIP-55 checksum.
+fn checksum(address: &Address) -> String {
+	let lower = hex::encode(address);
+	let hash = Keccak256::digest(lower.as_bytes());
+	let checksummed: String = lower
+		.chars()
+		.enumerate()
+		.map(|(i, c)| {
+			let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0xf;
+			if nibble >= 8 {
+				c.to_ascii_uppercase()
+			} else {
+				c
+			}
+		})
+		.collect();
+	format!("0x{checksummed}")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{checksum, signed};
+	use ethabi::{Address, Uint};
+
+	#[test]
+	fn eip55_checksum() {
+		let address: Address = "5aaeb6053f3e94c9b9a09f33669435e7ef1beaed".parse().unwrap();
+		assert_eq!(checksum(&address), "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+	}
+
+	#[test]
+	fn signed_decimal() {
+		assert_eq!(signed(&Uint::from(5)), "5");
+		assert_eq!(signed(&Uint::MAX), "-1");
+	}
+}
@@ -0,0 +1,292 @@
+//! Interactive exploration of a contract ABI.
+
+use std::{env, path::PathBuf};
+
+use anyhow::anyhow;
+use ethabi::{Contract, LogDecoder, RawLog, Revert};
+use itertools::Itertools;
+use rustyline::{
+	completion::Completer, error::ReadlineError, highlight::Highlighter, hint::Hinter, history::DefaultHistory,
+	validate::Validator, Context, Editor, Helper,
+};
+
+use crate::{
+	contract_selectors, decode_calldata_with, decoded, decoded_log, decoded_revert, encoded, find_function,
+	input_tokens, output::Format, parse_hex, Params,
+};
+
+const PROMPT: &str = "ethabi> ";
+
+const HELP: &str = "\
+functions                       list functions and their selectors
+events                          list events and their topics
+errors                          list errors and their selectors
+show <name|signature>           show the signatures and selectors of an item
+encode <function> [values...]   encode a function call, quoting values with spaces
+calldata <hex>                  decode a function call
+output <function> <hex>         decode the output of a function
+log [topics...] <hex>           decode a log by its first topic
+revert <hex>                    decode revert data
+help                            show this help
+exit                            leave the repl";
+
+const COMMANDS: &[&str] =
+	&["functions", "events", "errors", "show", "encode", "calldata", "output", "log", "revert", "help", "exit", "quit"];
+
+/// Runs the interactive prompt until `exit` or end of input, keeping the history in `~/.ethabi_history`.
+pub fn run(contract: Contract, format: Format) -> anyhow::Result<String> {
+	let mut editor = Editor::<Repl, DefaultHistory>::new()?;
+	editor.set_helper(Some(Repl::new(contract, format)));
+
+	let history = env::var_os("HOME").map(|home| PathBuf::from(home).join(".ethabi_history"));
+	// history is a convenience, a missing or unwritable file does not stop the repl
+	if let Some(ref history) = history {
+		let _ = editor.load_history(history);
+	}
+
+	loop {
+		let line = match editor.readline(PROMPT) {
+			Ok(line) => line,
+			Err(ReadlineError::Interrupted) => continue,
+			Err(ReadlineError::Eof) => break,
+			Err(err) => return Err(err.into()),
+		};
+		let line = line.trim();
+		if line.is_empty() {
+			continue;
+		}
+		let _ = editor.add_history_entry(line);
+		if line == "exit" || line == "quit" {
+			break;
+		}
+
+		match editor.helper().expect("helper is set above").eval(line) {
+			Ok(output) if output.is_empty() => {}
+			Ok(output) => println!("{output}"),
+			Err(err) => eprintln!("error: {err}"),
+		}
+	}
+
+	if let Some(ref history) = history {
+		let _ = editor.save_history(history);
+	}
+
+	Ok(String::new())
+}
+
+/// Contract loaded in the repl, completing the names of its commands and items.
+pub struct Repl {
+	contract: Contract,
+	format: Format,
+}
+
+impl Repl {
+	pub fn new(contract: Contract, format: Format) -> Self {
+		Repl { contract, format }
+	}
+
+	/// Evaluates a command line, returning its output.
+	pub fn eval(&self, line: &str) -> anyhow::Result<String> {
+		let words = split_words(line)?;
+		let (command, args) = match words.split_first() {
+			Some((command, args)) => (command.as_str(), args),
+			None => return Ok(String::new()),
+		};
+		let format = &self.format;
+
+		match (command, args) {
+			("help", []) => Ok(HELP.to_owned()),
+			("functions", []) => Ok(contract_selectors(&self.contract, format, |kind, _| kind == "function")),
+			("events", []) => Ok(contract_selectors(&self.contract, format, |kind, _| kind == "event")),
+			("errors", []) => Ok(contract_selectors(&self.contract, format, |kind, _| kind == "error")),
+			("show", [name]) => {
+				let output = contract_selectors(&self.contract, format, |_, signature| {
+					signature == name || signature.strip_prefix(name.as_str()).is_some_and(|s| s.starts_with('('))
+				});
+				match output.is_empty() || output == "[]" {
+					true => Err(anyhow!("no function, event or error `{name}`")),
+					false => Ok(output),
+				}
+			}
+			("encode", [name, values @ ..]) => {
+				let function = find_function(&self.contract, name)?;
+				let tokens = input_tokens(&function.inputs, &Params::Args(values.to_vec()), true)?;
+				Ok(encoded(&function.encode_input(&tokens)?, format))
+			}
+			("calldata", [data]) => {
+				let functions: Vec<_> = self.contract.functions().cloned().collect();
+				decode_calldata_with(&functions, &parse_hex(data)?, format)
+			}
+			("output", [name, data]) => {
+				let function = find_function(&self.contract, name)?;
+				let tokens = function.decode_output(&parse_hex(data)?)?;
+				Ok(decoded(&function.outputs, &tokens, format))
+			}
+			("log", [topics @ .., data]) => {
+				let topics = topics
+					.iter()
+					.map(|topic| Ok(parse_hex(topic)?.as_slice().try_into()?))
+					.collect::<anyhow::Result<Vec<[u8; 32]>>>()?;
+				let mut decoder = LogDecoder::new();
+				decoder.register_any("", &self.contract);
+				let log = RawLog { topics: topics.into_iter().map(Into::into).collect(), data: parse_hex(data)? };
+				let entry = (Default::default(), log).into();
+				match decoder.decode(&entry)? {
+					Some(decoded) => Ok(decoded_log(decoded.event, decoded.log, format)),
+					None => Err(anyhow!("no event matches the log topics")),
+				}
+			}
+			("revert", [data]) => {
+				let revert = Revert::decode_with_errors(&parse_hex(data)?, self.contract.errors());
+				Ok(decoded_revert(&revert, format))
+			}
+			_ if COMMANDS.contains(&command) => Err(anyhow!("invalid arguments for `{command}`, see `help`")),
+			_ => Err(anyhow!("unknown command `{command}`, see `help`")),
+		}
+	}
+
+	/// Returns the start of the word at `pos` and its completions: command names for the first word,
+	/// item names for the second.
+	fn candidates(&self, line: &str, pos: usize) -> (usize, Vec<String>) {
+		let line = &line[..pos];
+		let start = line.rfind(char::is_whitespace).map_or(0, |i| i + 1);
+		let (word, previous) = (&line[start..], line[..start].split_whitespace().collect::<Vec<_>>());
+
+		let names: Vec<String> = match previous.as_slice() {
+			[] => COMMANDS.iter().map(|command| command.to_string()).collect(),
+			["encode" | "output"] => self.contract.functions().map(|function| function.name.clone()).collect(),
+			["show"] => self
+				.contract
+				.functions()
+				.map(|function| &function.name)
+				.chain(self.contract.events().map(|event| &event.name))
+				.chain(self.contract.errors().map(|error| &error.name))
+				.cloned()
+				.collect(),
+			_ => Vec::new(),
+		};
+
+		(start, names.into_iter().filter(|name| name.starts_with(word)).sorted().dedup().collect())
+	}
+}
+
+impl Completer for Repl {
+	type Candidate = String;
+
+	fn complete(&self, line: &str, pos: usize, _: &Context<'_>) -> rustyline::Result<(usize, Vec<String>)> {
+		Ok(self.candidates(line, pos))
+	}
+}
+
+impl Hinter for Repl {
+	type Hint = String;
+}
+
+impl Highlighter for Repl {}
+
+impl Validator for Repl {}
+
+impl Helper for Repl {}
+
+/// Splits a command line on whitespace, keeping double quoted words together.
+fn split_words(line: &str) -> anyhow::Result<Vec<String>> {
+	let mut words = Vec::new();
+	let mut word: Option<String> = None;
+	let mut quoted = false;
+
+	for c in line.chars() {
+		match c {
+			'"' => {
+				quoted = !quoted;
+				word.get_or_insert_with(String::new);
+			}
+			c if c.is_whitespace() && !quoted => words.extend(word.take()),
+			c => word.get_or_insert_with(String::new).push(c),
+		}
+	}
+	if quoted {
+		return Err(anyhow!("unterminated quote"));
+	}
+	words.extend(word);
+
+	Ok(words)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{split_words, Repl};
+	use crate::{load_contract, output::Format};
+
+	fn repl(format: Format) -> Repl {
+		Repl::new(load_contract("../res/eip20.abi").unwrap(), format)
+	}
+
+	#[test]
+	fn split_quoted_words() {
+		let words = split_words(r#"encode  setGreeting "hello world" """#).unwrap();
+		assert_eq!(words, vec!["encode", "setGreeting", "hello world", ""]);
+		assert!(split_words(r#"encode "hi"#).is_err());
+	}
+
+	#[test]
+	fn list_and_show() {
+		let repl = repl(Format::default());
+		let events = "8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925 event Approval(address,address,uint256)\n\
+			ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef event Transfer(address,address,uint256)";
+		assert_eq!(repl.eval("events").unwrap(), events);
+		assert_eq!(repl.eval("show transfer").unwrap(), "a9059cbb function transfer(address,uint256)");
+		assert_eq!(repl.eval("show transfer(address,uint256)").unwrap(), "a9059cbb function transfer(address,uint256)");
+		assert!(repl.eval("show transfe").is_err());
+		assert!(repl.eval("errors").unwrap().is_empty());
+	}
+
+	#[test]
+	fn encode_and_decode() {
+		let repl = repl(Format::default());
+		let calldata = repl.eval("encode balanceOf 0x1111111111111111111111111111111111111111").unwrap();
+		assert_eq!(calldata, "70a082310000000000000000000000001111111111111111111111111111111111111111");
+		assert_eq!(
+			repl.eval(&format!("calldata 0x{calldata}")).unwrap(),
+			"balanceOf(address)\n_owner 1111111111111111111111111111111111111111"
+		);
+
+		let output = "0000000000000000000000000000000000000000000000000000000000000100";
+		assert_eq!(repl.eval(&format!("output balanceOf {output}")).unwrap(), "uint256 100");
+
+		assert!(repl.eval("encode balanceOf").is_err());
+		assert!(repl.eval("encode nope").is_err());
+		assert!(repl.eval("nope").is_err());
+	}
+
+	#[test]
+	fn decode_log_and_revert() {
+		let repl = repl(Format { decimal: true, ..Default::default() });
+		let command = "log \
+			0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef \
+			0x0000000000000000000000001111111111111111111111111111111111111111 \
+			0x0000000000000000000000002222222222222222222222222222222222222222 \
+			0x0000000000000000000000000000000000000000000000000000000000000007";
+		assert_eq!(
+			repl.eval(command).unwrap(),
+			"from 1111111111111111111111111111111111111111\nto 2222222222222222222222222222222222222222\nvalue 7"
+		);
+
+		let revert = "08c379a0\
+			0000000000000000000000000000000000000000000000000000000000000020\
+			0000000000000000000000000000000000000000000000000000000000000004\
+			6e6f706500000000000000000000000000000000000000000000000000000000";
+		assert_eq!(repl.eval(&format!("revert {revert}")).unwrap(), "Error(string)\nreason nope");
+	}
+
+	#[test]
+	fn complete_names() {
+		let repl = repl(Format::default());
+		assert_eq!(
+			repl.candidates("e", 1),
+			(0, vec!["encode".to_owned(), "errors".to_owned(), "events".to_owned(), "exit".to_owned()])
+		);
+		assert_eq!(repl.candidates("encode tra", 10), (7, vec!["transfer".to_owned(), "transferFrom".to_owned()]));
+		assert_eq!(repl.candidates("show Tr", 7), (5, vec!["Transfer".to_owned()]));
+		assert_eq!(repl.candidates("encode transfer 0x", 18), (16, vec![]));
+	}
+}
@@ -109,7 +109,12 @@ mod tests {
 	#[test]
 	fn test_one_param() {
 		let ethabi_constructor = ethabi::Constructor {
-			inputs: vec![ethabi::Param { name: "foo".into(), kind: ethabi::ParamType::Uint(256), internal_type: None }],
+			inputs: vec![ethabi::Param {
+				name: "foo".into(),
+				kind: ethabi::ParamType::Uint(256),
+				internal_type: None,
+				components: vec![],
+			}],
 		};
 
 		let c = Constructor::from(&ethabi_constructor);
@@ -121,7 +126,8 @@ mod tests {
 					inputs: vec![ethabi::Param {
 						name: "foo".to_owned(),
 						kind: ethabi::ParamType::Uint(256usize),
-						internal_type: None
+						internal_type: None,
+						components: vec![]
 					}],
 				};
 				let tokens = vec![ethabi::Token::Uint(foo.into())];
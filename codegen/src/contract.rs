@@ -6,6 +6,9 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::borrow::Cow;
+
+use ethabi::{Error, ParamType};
 use proc_macro2::TokenStream;
 use quote::quote;
 
@@ -18,14 +21,44 @@ pub struct Contract {
 	events: Vec<Event>,
 }
 
-impl<'a> From<&'a ethabi::Contract> for Contract {
-	fn from(c: &'a ethabi::Contract) -> Self {
-		Contract {
+impl<'a> TryFrom<&'a ethabi::Contract> for Contract {
+	type Error = Error;
+
+	/// Fails if the contract has tuple params, which are not supported yet.
+	fn try_from(c: &'a ethabi::Contract) -> ethabi::Result<Self> {
+		if let Some(ref constructor) = c.constructor {
+			check_params("constructor", constructor.inputs.iter().map(|param| &param.kind))?;
+		}
+		for function in c.functions() {
+			check_params(&function.name, function.inputs.iter().chain(&function.outputs).map(|param| &param.kind))?;
+		}
+		for event in c.events() {
+			check_params(&event.name, event.inputs.iter().map(|param| &param.kind))?;
+		}
+
+		Ok(Contract {
 			constructor: c.constructor.as_ref().map(Into::into),
 			functions: c.functions().map(Into::into).collect(),
 			events: c.events().map(Into::into).collect(),
+		})
+	}
+}
+
+fn check_params<'a>(item: &str, mut kinds: impl Iterator<Item = &'a ParamType>) -> ethabi::Result<()> {
+	fn has_tuple(kind: &ParamType) -> bool {
+		match *kind {
+			ParamType::Tuple(_) => true,
+			ParamType::Array(ref kind) | ParamType::FixedArray(ref kind, _) => has_tuple(kind),
+			_ => false,
 		}
 	}
+
+	match kinds.any(has_tuple) {
+		true => Err(Error::Other(Cow::Owned(format!(
+			"`{item}` has tuple params, which are not supported. https://github.com/openethereum/ethabi/issues/175"
+		)))),
+		false => Ok(()),
+	}
 }
 
 impl Contract {
@@ -80,7 +113,7 @@ mod test {
 			fallback: false,
 		};
 
-		let c = Contract::from(&ethabi_contract);
+		let c = Contract::try_from(&ethabi_contract).unwrap();
 
 		let expected = quote! {
 			use ethabi;
@@ -105,4 +138,12 @@ mod test {
 
 		assert_eq!(expected.to_string(), c.generate().to_string());
 	}
+
+	#[test]
+	fn test_tuple_params() {
+		let abi = r#"[{"type":"function","name":"aggregate","inputs":[{"name":"calls","type":"tuple[]","components":[{"name":"target","type":"address"}]}],"outputs":[]}]"#;
+		let ethabi_contract = ethabi::Contract::load(abi.as_bytes()).unwrap();
+		let err = Contract::try_from(&ethabi_contract).err().unwrap();
+		assert!(err.to_string().contains("`aggregate` has tuple params"), "{err}");
+	}
 }
@@ -25,6 +25,18 @@ pub struct Event {
 	anonymous: bool,
 }
 
+fn is_hashed_topic(param: &ethabi::EventParam) -> bool {
+	param.indexed
+		&& matches!(
+			param.kind,
+			ethabi::ParamType::String
+				| ethabi::ParamType::Bytes
+				| ethabi::ParamType::Array(_)
+				| ethabi::ParamType::FixedArray(..)
+				| ethabi::ParamType::Tuple(_)
+		)
+}
+
 impl<'a> From<&'a ethabi::Event> for Event {
 	fn from(e: &'a ethabi::Event) -> Self {
 		let names: Vec<_> = e
@@ -43,13 +55,28 @@ impl<'a> From<&'a ethabi::Event> for Event {
 				}
 			})
 			.collect();
-		let kinds: Vec<_> = e.inputs.iter().map(|param| rust_type(&param.kind)).collect();
+		// indexed dynamic params are only available as the hash stored in their topic
+		let kinds: Vec<_> = e
+			.inputs
+			.iter()
+			.map(|param| match is_hashed_topic(param) {
+				true => quote! { ethabi::Hash },
+				false => rust_type(&param.kind),
+			})
+			.collect();
 		let log_fields =
 			names.iter().zip(kinds.iter()).map(|(param_name, kind)| quote! { pub #param_name: #kind }).collect();
 
 		let log_iter = quote! { log.next().expect(INTERNAL_ERR).value };
 
-		let to_log: Vec<_> = e.inputs.iter().map(|param| from_token(&param.kind, &log_iter)).collect();
+		let to_log: Vec<_> = e
+			.inputs
+			.iter()
+			.map(|param| match is_hashed_topic(param) {
+				true => quote! { #log_iter.into_hashed_topic().expect(INTERNAL_ERR) },
+				false => from_token(&param.kind, &log_iter),
+			})
+			.collect();
 
 		let log_init =
 			names.iter().zip(to_log.iter()).map(|(param_name, convert)| quote! { #param_name: #convert }).collect();
@@ -88,11 +115,13 @@ impl<'a> From<&'a ethabi::Event> for Event {
 		// The number of parameters that creates a filter which matches anything.
 		let wildcard_filter_params: Vec<_> = filter_definitions.iter().map(|_| quote! { ethabi::Topic::Any }).collect();
 
+		// anonymous events have no signature topic, leaving four topics for indexed params
+		let max_topics = if e.anonymous { 4 } else { 3 };
 		let filter_init: Vec<_> = topic_names
 			.iter()
 			.zip(e.inputs.iter().filter(|p| p.indexed))
 			.enumerate()
-			.take(3)
+			.take(max_topics)
 			.map(|(index, (param_name, param))| {
 				let topic = syn::Ident::new(&format!("topic{index}"), Span::call_site());
 				let i = quote! { i };
@@ -113,7 +142,7 @@ impl<'a> From<&'a ethabi::Event> for Event {
 					ethabi::EventParam {
 						name: #name.to_owned(),
 						kind: #kind,
-						indexed: #indexed
+						indexed: #indexed, components: vec![]
 					}
 				}
 			})
@@ -268,7 +297,12 @@ mod tests {
 	fn test_event_with_one_input() {
 		let ethabi_event = ethabi::Event {
 			name: "one".into(),
-			inputs: vec![ethabi::EventParam { name: "foo".into(), kind: ethabi::ParamType::Address, indexed: true }],
+			inputs: vec![ethabi::EventParam {
+				name: "foo".into(),
+				kind: ethabi::ParamType::Address,
+				indexed: true,
+				components: vec![],
+			}],
 			anonymous: false,
 		};
 
@@ -285,7 +319,7 @@ mod tests {
 						inputs: vec![ethabi::EventParam {
 							name: "foo".to_owned(),
 							kind: ethabi::ParamType::Address,
-							indexed: true
+							indexed: true, components: vec![]
 						}],
 						anonymous: false,
 					}
@@ -319,11 +353,39 @@ mod tests {
 		assert_eq!(expected.to_string(), e.generate_event().to_string());
 	}
 
+	#[test]
+	fn test_anonymous_event_filters_four_topics() {
+		let inputs: Vec<_> = ["a", "b", "c", "d"]
+			.iter()
+			.map(|name| ethabi::EventParam {
+				name: (*name).into(),
+				kind: ethabi::ParamType::Address,
+				indexed: true,
+				components: vec![],
+			})
+			.collect();
+
+		let ethabi_event = ethabi::Event { name: "four".into(), inputs: inputs.clone(), anonymous: true };
+		let e = Event::from(&ethabi_event);
+
+		let expected = quote! { topic3: d.into().map(|i| ethabi::Token::Address(i)), };
+		assert_eq!(e.filter_init.len(), 4);
+		assert_eq!(expected.to_string(), e.filter_init[3].to_string());
+
+		let ethabi_event = ethabi::Event { name: "four".into(), inputs, anonymous: false };
+		assert_eq!(Event::from(&ethabi_event).filter_init.len(), 3);
+	}
+
 	#[test]
 	fn test_log_with_one_field() {
 		let ethabi_event = ethabi::Event {
 			name: "one".into(),
-			inputs: vec![ethabi::EventParam { name: "foo".into(), kind: ethabi::ParamType::Address, indexed: false }],
+			inputs: vec![ethabi::EventParam {
+				name: "foo".into(),
+				kind: ethabi::ParamType::Address,
+				indexed: false,
+				components: vec![],
+			}],
 			anonymous: false,
 		};
 
@@ -339,18 +401,58 @@ mod tests {
 		assert_eq!(expected.to_string(), e.generate_log().to_string());
 	}
 
+	#[test]
+	fn test_log_with_hashed_topic() {
+		let ethabi_event = ethabi::Event {
+			name: "one".into(),
+			inputs: vec![ethabi::EventParam {
+				name: "foo".into(),
+				kind: ethabi::ParamType::String,
+				indexed: true,
+				components: vec![],
+			}],
+			anonymous: false,
+		};
+
+		let e = Event::from(&ethabi_event);
+
+		let expected = quote! {
+			#[derive(Debug, Clone, PartialEq, Eq)]
+			pub struct One {
+				pub foo: ethabi::Hash
+			}
+		};
+
+		assert_eq!(expected.to_string(), e.generate_log().to_string());
+		assert_eq!(
+			quote! { foo: log.next().expect(INTERNAL_ERR).value.into_hashed_topic().expect(INTERNAL_ERR) }.to_string(),
+			e.log_init[0].to_string()
+		);
+	}
+
 	#[test]
 	fn test_log_with_multiple_field() {
 		let ethabi_event = ethabi::Event {
 			name: "many".into(),
 			inputs: vec![
-				ethabi::EventParam { name: "foo".into(), kind: ethabi::ParamType::Address, indexed: false },
+				ethabi::EventParam {
+					name: "foo".into(),
+					kind: ethabi::ParamType::Address,
+					indexed: false,
+					components: vec![],
+				},
 				ethabi::EventParam {
 					name: "bar".into(),
 					kind: ethabi::ParamType::Array(Box::new(ethabi::ParamType::String)),
 					indexed: false,
+					components: vec![],
+				},
+				ethabi::EventParam {
+					name: "xyz".into(),
+					kind: ethabi::ParamType::Uint(256),
+					indexed: false,
+					components: vec![],
 				},
-				ethabi::EventParam { name: "xyz".into(), kind: ethabi::ParamType::Uint(256), indexed: false },
 			],
 			anonymous: false,
 		};
@@ -288,11 +288,17 @@ mod tests {
 		#[allow(deprecated)]
 		let ethabi_function = ethabi::Function {
 			name: "hello".into(),
-			inputs: vec![ethabi::Param { name: "foo".into(), kind: ethabi::ParamType::Address, internal_type: None }],
+			inputs: vec![ethabi::Param {
+				name: "foo".into(),
+				kind: ethabi::ParamType::Address,
+				internal_type: None,
+				components: vec![],
+			}],
 			outputs: vec![ethabi::Param {
 				name: "bar".into(),
 				kind: ethabi::ParamType::Uint(256),
 				internal_type: None,
+				components: vec![],
 			}],
 			constant: None,
 			state_mutability: ethabi::StateMutability::Payable,
@@ -311,12 +317,14 @@ mod tests {
 						inputs: vec![ethabi::Param {
 							name: "foo".to_owned(),
 							kind: ethabi::ParamType::Address,
-							internal_type: None
+							internal_type: None,
+							components: vec![]
 						}],
 						outputs: vec![ethabi::Param {
 							name: "bar".to_owned(),
 							kind: ethabi::ParamType::Uint(256usize),
-							internal_type: None
+							internal_type: None,
+							components: vec![]
 						}],
 						constant: Some(false),
 						state_mutability: ::ethabi::StateMutability::Payable
@@ -369,16 +377,28 @@ mod tests {
 					name: "foo".into(),
 					kind: ethabi::ParamType::FixedArray(Box::new(ethabi::ParamType::Address), 2),
 					internal_type: None,
+					components: vec![],
 				},
 				ethabi::Param {
 					name: "bar".into(),
 					kind: ethabi::ParamType::Array(Box::new(ethabi::ParamType::Uint(256))),
 					internal_type: None,
+					components: vec![],
 				},
 			],
 			outputs: vec![
-				ethabi::Param { name: "".into(), kind: ethabi::ParamType::Uint(256), internal_type: None },
-				ethabi::Param { name: "".into(), kind: ethabi::ParamType::String, internal_type: None },
+				ethabi::Param {
+					name: "".into(),
+					kind: ethabi::ParamType::Uint(256),
+					internal_type: None,
+					components: vec![],
+				},
+				ethabi::Param {
+					name: "".into(),
+					kind: ethabi::ParamType::String,
+					internal_type: None,
+					components: vec![],
+				},
 			],
 			constant: None,
 			state_mutability: ethabi::StateMutability::Payable,
@@ -397,20 +417,24 @@ mod tests {
 						inputs: vec![ethabi::Param {
 							name: "foo".to_owned(),
 							kind: ethabi::ParamType::FixedArray(Box::new(ethabi::ParamType::Address), 2usize),
-							internal_type: None
+							internal_type: None,
+							components: vec![]
 						}, ethabi::Param {
 							name: "bar".to_owned(),
 							kind: ethabi::ParamType::Array(Box::new(ethabi::ParamType::Uint(256usize))),
-							internal_type: None
+							internal_type: None,
+							components: vec![]
 						}],
 						outputs: vec![ethabi::Param {
 							name: "".to_owned(),
 							kind: ethabi::ParamType::Uint(256usize),
-							internal_type: None
+							internal_type: None,
+							components: vec![]
 						}, ethabi::Param {
 							name: "".to_owned(),
 							kind: ethabi::ParamType::String,
-							internal_type: None
+							internal_type: None,
+							components: vec![]
 						}],
 						constant: Some(false),
 						state_mutability: ::ethabi::StateMutability::Payable
@@ -0,0 +1,441 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Lossless ABI document model.
+
+use std::{fmt, io};
+
+use serde::{
+	de::{self, MapAccess, Visitor},
+	ser::SerializeMap,
+	Deserialize, Deserializer, Serialize, Serializer,
+};
+use serde_json::value::RawValue;
+
+use crate::{errors, Contract, StateMutability};
+
+/// ABI json file kept exactly as written.
+///
+/// Unlike [`Contract`], which groups items by name and normalizes them, a document keeps the order
+/// of the items, the order of the fields of items and params, legacy fields like `constant` and
+/// `payable`, and fields unknown to this crate, whose values are kept as raw json. Serializing a
+/// loaded document reproduces the original json up to whitespace outside of strings, and
+/// byte-for-byte when formatted like it, e.g. the minified ABIs of `solc --abi`.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AbiDocument {
+	/// Items of the ABI, in document order.
+	pub items: Vec<AbiItem>,
+}
+
+/// Single item of an ABI document: function, constructor, event, error, fallback or receive.
+#[derive(Debug, Clone, Default)]
+pub struct AbiItem {
+	/// Item type, a function when omitted.
+	pub item_type: Option<String>,
+	/// Item name.
+	pub name: Option<String>,
+	/// Input params.
+	pub inputs: Option<Vec<AbiParam>>,
+	/// Output params.
+	pub outputs: Option<Vec<AbiParam>>,
+	/// State mutability.
+	pub state_mutability: Option<StateMutability>,
+	/// Legacy `constant` field.
+	pub constant: Option<bool>,
+	/// Legacy `payable` field.
+	pub payable: Option<bool>,
+	/// Whether an event is anonymous.
+	pub anonymous: Option<bool>,
+	/// Fields unknown to this crate, in document order, with their values as written.
+	pub extra: Vec<(String, Box<RawValue>)>,
+	/// Order of the fields in the document.
+	order: Vec<String>,
+}
+
+/// Param of an ABI item, or component of a tuple param.
+#[derive(Debug, Clone, Default)]
+pub struct AbiParam {
+	/// Param name.
+	pub name: Option<String>,
+	/// Canonical type, e.g. `uint256` or `tuple[]`.
+	pub kind: String,
+	/// Additional internal type.
+	pub internal_type: Option<String>,
+	/// Whether an event param is indexed.
+	pub indexed: Option<bool>,
+	/// Components of a tuple param.
+	pub components: Option<Vec<AbiParam>>,
+	/// Fields unknown to this crate, in document order, with their values as written.
+	pub extra: Vec<(String, Box<RawValue>)>,
+	/// Order of the fields in the document.
+	order: Vec<String>,
+}
+
+impl AbiDocument {
+	/// Loads an ABI document from json.
+	pub fn load<T: io::Read>(reader: T) -> errors::Result<Self> {
+		serde_json::from_reader(reader).map_err(From::from)
+	}
+
+	/// Converts the document to a [`Contract`].
+	pub fn contract(&self) -> errors::Result<Contract> {
+		serde_json::from_value(serde_json::to_value(self)?).map_err(From::from)
+	}
+}
+
+impl AbiItem {
+	const FIELDS: &'static [&'static str] =
+		&["type", "name", "inputs", "outputs", "stateMutability", "constant", "payable", "anonymous"];
+	const REQUIRED: &'static [&'static str] = &[];
+
+	/// Returns the item type, applying the `function` default.
+	pub fn item_type(&self) -> &str {
+		self.item_type.as_deref().unwrap_or("function")
+	}
+
+	fn serialize_field<M: SerializeMap>(&self, key: &str, map: &mut M) -> Result<(), M::Error> {
+		match key {
+			"type" => serialize_entry(map, key, &self.item_type, &self.extra),
+			"name" => serialize_entry(map, key, &self.name, &self.extra),
+			"inputs" => serialize_entry(map, key, &self.inputs, &self.extra),
+			"outputs" => serialize_entry(map, key, &self.outputs, &self.extra),
+			"stateMutability" => serialize_entry(map, key, &self.state_mutability, &self.extra),
+			"constant" => serialize_entry(map, key, &self.constant, &self.extra),
+			"payable" => serialize_entry(map, key, &self.payable, &self.extra),
+			"anonymous" => serialize_entry(map, key, &self.anonymous, &self.extra),
+			_ => serialize_extra(map, key, &self.extra),
+		}
+	}
+
+	fn deserialize_field<'a, A: MapAccess<'a>>(&mut self, key: &str, map: &mut A) -> Result<(), A::Error> {
+		match key {
+			"type" => deserialize_entry(map, key, &mut self.item_type, &mut self.extra),
+			"name" => deserialize_entry(map, key, &mut self.name, &mut self.extra),
+			"inputs" => deserialize_entry(map, key, &mut self.inputs, &mut self.extra),
+			"outputs" => deserialize_entry(map, key, &mut self.outputs, &mut self.extra),
+			"stateMutability" => deserialize_entry(map, key, &mut self.state_mutability, &mut self.extra),
+			"constant" => deserialize_entry(map, key, &mut self.constant, &mut self.extra),
+			"payable" => deserialize_entry(map, key, &mut self.payable, &mut self.extra),
+			"anonymous" => deserialize_entry(map, key, &mut self.anonymous, &mut self.extra),
+			_ => deserialize_extra(map, key, &mut self.extra),
+		}
+	}
+}
+
+impl PartialEq for AbiItem {
+	fn eq(&self, other: &Self) -> bool {
+		self.item_type == other.item_type
+			&& self.name == other.name
+			&& self.inputs == other.inputs
+			&& self.outputs == other.outputs
+			&& self.state_mutability == other.state_mutability
+			&& self.constant == other.constant
+			&& self.payable == other.payable
+			&& self.anonymous == other.anonymous
+			&& extra_eq(&self.extra, &other.extra)
+			&& self.order == other.order
+	}
+}
+
+impl AbiParam {
+	const FIELDS: &'static [&'static str] = &["name", "type", "internalType", "indexed", "components"];
+	const REQUIRED: &'static [&'static str] = &["type"];
+
+	fn serialize_field<M: SerializeMap>(&self, key: &str, map: &mut M) -> Result<(), M::Error> {
+		match key {
+			"name" => serialize_entry(map, key, &self.name, &self.extra),
+			"type" => map.serialize_entry(key, &self.kind),
+			"internalType" => serialize_entry(map, key, &self.internal_type, &self.extra),
+			"indexed" => serialize_entry(map, key, &self.indexed, &self.extra),
+			"components" => serialize_entry(map, key, &self.components, &self.extra),
+			_ => serialize_extra(map, key, &self.extra),
+		}
+	}
+
+	fn deserialize_field<'a, A: MapAccess<'a>>(&mut self, key: &str, map: &mut A) -> Result<(), A::Error> {
+		match key {
+			"name" => deserialize_entry(map, key, &mut self.name, &mut self.extra),
+			"type" => {
+				self.kind = map.next_value()?;
+				Ok(())
+			}
+			"internalType" => deserialize_entry(map, key, &mut self.internal_type, &mut self.extra),
+			"indexed" => deserialize_entry(map, key, &mut self.indexed, &mut self.extra),
+			"components" => deserialize_entry(map, key, &mut self.components, &mut self.extra),
+			_ => deserialize_extra(map, key, &mut self.extra),
+		}
+	}
+}
+
+impl PartialEq for AbiParam {
+	fn eq(&self, other: &Self) -> bool {
+		self.name == other.name
+			&& self.kind == other.kind
+			&& self.internal_type == other.internal_type
+			&& self.indexed == other.indexed
+			&& self.components == other.components
+			&& extra_eq(&self.extra, &other.extra)
+			&& self.order == other.order
+	}
+}
+
+/// Implements order preserving (de)serialization for a document object.
+macro_rules! ordered_object {
+	($object:ident, $expecting:expr) => {
+		impl Serialize for $object {
+			fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+			where
+				S: Serializer,
+			{
+				let mut map = serializer.serialize_map(None)?;
+				for key in &self.order {
+					self.serialize_field(key, &mut map)?;
+				}
+				// fields set after loading, or on documents built in code
+				let listed = |key: &str| self.order.iter().chain(self.extra.iter().map(|(k, _)| k)).any(|k| k == key);
+				for key in $object::FIELDS.iter().filter(|key| !listed(key)) {
+					self.serialize_field(key, &mut map)?;
+				}
+				for (key, value) in self.extra.iter().filter(|(key, _)| !self.order.contains(key)) {
+					map.serialize_entry(key, value)?;
+				}
+				map.end()
+			}
+		}
+
+		impl<'a> Deserialize<'a> for $object {
+			fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+			where
+				D: Deserializer<'a>,
+			{
+				struct ObjectVisitor;
+
+				impl<'a> Visitor<'a> for ObjectVisitor {
+					type Value = $object;
+
+					fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+						formatter.write_str($expecting)
+					}
+
+					fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+					where
+						A: MapAccess<'a>,
+					{
+						let mut object = $object::default();
+						while let Some(key) = map.next_key::<String>()? {
+							if object.order.contains(&key) {
+								return Err(de::Error::custom(format!("duplicate field `{key}`")));
+							}
+							object.deserialize_field(&key, &mut map)?;
+							object.order.push(key);
+						}
+						if let Some(key) = $object::REQUIRED.iter().find(|key| !object.order.iter().any(|k| k == *key))
+						{
+							return Err(de::Error::missing_field(key));
+						}
+						Ok(object)
+					}
+				}
+
+				deserializer.deserialize_map(ObjectVisitor)
+			}
+		}
+	};
+}
+
+ordered_object!(AbiItem, "abi item object");
+ordered_object!(AbiParam, "abi param object");
+
+/// Compares unknown fields by their raw json.
+fn extra_eq(left: &[(String, Box<RawValue>)], right: &[(String, Box<RawValue>)]) -> bool {
+	left.len() == right.len() && left.iter().zip(right).all(|((k1, v1), (k2, v2))| k1 == k2 && v1.get() == v2.get())
+}
+
+fn serialize_entry<M: SerializeMap, T: Serialize>(
+	map: &mut M,
+	key: &str,
+	value: &Option<T>,
+	extra: &[(String, Box<RawValue>)],
+) -> Result<(), M::Error> {
+	match value {
+		Some(value) => map.serialize_entry(key, value),
+		None => serialize_extra(map, key, extra),
+	}
+}
+
+fn serialize_extra<M: SerializeMap>(map: &mut M, key: &str, extra: &[(String, Box<RawValue>)]) -> Result<(), M::Error> {
+	match extra.iter().find(|(k, _)| k == key) {
+		Some((key, value)) => map.serialize_entry(key, value),
+		None => Ok(()),
+	}
+}
+
+/// Deserializes a known field, keeping an explicit `null` as an unknown field so it is not lost.
+fn deserialize_entry<'a, A: MapAccess<'a>, T: Deserialize<'a>>(
+	map: &mut A,
+	key: &str,
+	field: &mut Option<T>,
+	extra: &mut Vec<(String, Box<RawValue>)>,
+) -> Result<(), A::Error> {
+	match map.next_value()? {
+		Some(value) => *field = Some(value),
+		None => extra.push((key.to_owned(), RawValue::NULL.to_owned())),
+	}
+	Ok(())
+}
+
+fn deserialize_extra<'a, A: MapAccess<'a>>(
+	map: &mut A,
+	key: &str,
+	extra: &mut Vec<(String, Box<RawValue>)>,
+) -> Result<(), A::Error> {
+	extra.push((key.to_owned(), map.next_value()?));
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use serde::Serialize;
+	use serde_json::{ser::PrettyFormatter, value::RawValue, Serializer};
+
+	use super::{AbiDocument, AbiItem};
+	use crate::StateMutability;
+
+	/// Removes whitespace outside of strings.
+	fn minify(json: &str) -> String {
+		let mut minified = String::with_capacity(json.len());
+		let (mut in_string, mut escaped) = (false, false);
+		for c in json.chars() {
+			match c {
+				_ if escaped => escaped = false,
+				'\\' if in_string => escaped = true,
+				'"' => in_string = !in_string,
+				c if c.is_whitespace() && !in_string => continue,
+				_ => {}
+			}
+			minified.push(c);
+		}
+		minified
+	}
+
+	/// Serializes the document pretty printed with the given indent.
+	fn to_string_pretty(document: &AbiDocument, indent: &[u8]) -> String {
+		let mut serializer = Serializer::with_formatter(Vec::new(), PrettyFormatter::with_indent(indent));
+		document.serialize(&mut serializer).unwrap();
+		String::from_utf8(serializer.into_inner()).unwrap()
+	}
+
+	/// Asserts that serializing the loaded json gives back its bytes, up to whitespace outside of
+	/// strings.
+	fn assert_round_trip(json: &str) -> AbiDocument {
+		let document = AbiDocument::load(json.as_bytes()).unwrap();
+		assert_eq!(serde_json::to_string(&document).unwrap(), minify(json));
+		document
+	}
+
+	/// Asserts that serializing the loaded file, formatted like it, gives back its exact bytes.
+	fn assert_exact_round_trip(file: &str, indent: Option<&[u8]>) {
+		let document = AbiDocument::load(file.as_bytes()).unwrap();
+		let json = match indent {
+			Some(indent) => to_string_pretty(&document, indent),
+			None => serde_json::to_string(&document).unwrap(),
+		};
+		assert_eq!(json + "\n", file);
+		document.contract().unwrap();
+	}
+
+	#[test]
+	fn round_trip_res_corpus_byte_for_byte() {
+		assert_exact_round_trip(include_str!("../../res/Multicall3.abi"), None);
+		assert_exact_round_trip(include_str!("../../res/Operations.abi"), Some(b"\t"));
+		assert_exact_round_trip(include_str!("../../res/constructor.abi"), Some(b"    "));
+		assert_exact_round_trip(include_str!("../../res/eip20.abi"), Some(b"    "));
+		assert_exact_round_trip(include_str!("../../res/errors.abi"), Some(b"    "));
+		assert_exact_round_trip(include_str!("../../res/event.abi"), Some(b"    "));
+		assert_exact_round_trip(include_str!("../../res/foo.abi"), Some(b"    "));
+		assert_exact_round_trip(include_str!("../../res/test_rust_keywords.abi"), Some(b"    "));
+		assert_exact_round_trip(include_str!("../../res/tuple_event.abi"), Some(b"    "));
+		assert_exact_round_trip(include_str!("../../res/Validators.abi"), Some(b"    "));
+
+		// the abi of a hardhat artifact, nested one level deep
+		#[derive(serde::Deserialize)]
+		struct Artifact<'a> {
+			#[serde(borrow)]
+			abi: &'a RawValue,
+		}
+		let artifact: Artifact = serde_json::from_str(include_str!("../../res/Greeter.json")).unwrap();
+		let document = AbiDocument::load(artifact.abi.get().as_bytes()).unwrap();
+		assert_eq!(to_string_pretty(&document, b"  ").replace('\n', "\n  "), artifact.abi.get());
+	}
+
+	#[test]
+	fn round_trip_hand_formatted_ignoring_whitespace() {
+		assert_round_trip(include_str!("../../res/test.abi")).contract().unwrap();
+		assert_round_trip(include_str!("../../res/urlhint.abi")).contract().unwrap();
+	}
+
+	#[test]
+	fn round_trip_order_legacy_and_unknown_fields() {
+		let json = r#"[
+			{"type":"function","name":"foo","constant":true,"payable":false,"inputs":[],"outputs":[{"type":"uint256","name":"","x-doc":"total"}],"gas":2300},
+			{"name":"Bar","type":"event","inputs":[{"indexed":true,"name":"a","internalType":"address","type":"address"}],"anonymous":false},
+			{"type":"error","name":"Baz","inputs":[{"name":"p","type":"tuple","components":[{"name":"q","type":"string"}],"internalType":"struct P"}]},
+			{"type":"receive","stateMutability":"payable"},
+			{"type":"fallback","stateMutability":"nonpayable"}
+		]"#;
+		let document = assert_round_trip(json);
+		assert_eq!(document.items[0].constant, Some(true));
+		assert_eq!(document.items[0].extra.len(), 1);
+		assert_eq!(document.items[0].extra[0].0, "gas");
+		assert_eq!(document.items[0].extra[0].1.get(), "2300");
+		assert_eq!(document.items[1].item_type(), "event");
+		assert_eq!(document.items[2].inputs.as_ref().unwrap()[0].components.as_ref().unwrap()[0].kind, "string");
+
+		let contract = document.contract().unwrap();
+		assert!(contract.receive && contract.fallback);
+		assert_eq!(contract.event("Bar").unwrap().inputs[0].name, "a");
+	}
+
+	#[test]
+	fn unknown_values_kept_as_written() {
+		let json = r#"[{"type":"function","name":"foo","inputs":[{"name":"a","type":"uint256","x-range":{"max":1.0e3,"min":-0.0}}],"outputs":[],"devdoc":{"z":[1E30,18446744073709551616],"a":"é"}}]"#;
+		let document = AbiDocument::load(json.as_bytes()).unwrap();
+		assert_eq!(serde_json::to_string(&document).unwrap(), json);
+		assert_eq!(document.items[0].extra[0].1.get(), r#"{"z":[1E30,18446744073709551616],"a":"é"}"#);
+		assert_eq!(document, AbiDocument::load(json.as_bytes()).unwrap());
+		document.contract().unwrap();
+	}
+
+	#[test]
+	fn serialize_modified() {
+		let mut document = AbiDocument::load(r#"[{"name":"foo","inputs":[],"outputs":[]}]"#.as_bytes()).unwrap();
+		assert_eq!(document.items[0].item_type(), "function");
+
+		document.items[0].name = None;
+		document.items[0].state_mutability = Some(StateMutability::View);
+		document.items.push(AbiItem { item_type: Some("receive".to_owned()), ..Default::default() });
+
+		let expected = r#"[{"inputs":[],"outputs":[],"stateMutability":"view"},{"type":"receive"}]"#;
+		assert_eq!(serde_json::to_string(&document).unwrap(), expected);
+	}
+
+	#[test]
+	fn null_and_duplicate_fields() {
+		let document = assert_round_trip(r#"[{"type":"function","name":"foo","inputs":[],"outputs":null}]"#);
+		assert_eq!(document.items[0].outputs, None);
+		assert!(AbiDocument::load(r#"[{"name":"foo","name":"bar"}]"#.as_bytes()).is_err());
+	}
+
+	#[test]
+	fn param_without_type() {
+		let err = AbiDocument::load(r#"[{"name":"foo","inputs":[{"name":"a"}]}]"#.as_bytes()).unwrap_err();
+		assert!(err.to_string().contains("missing field `type`"), "{err}");
+		assert!(AbiDocument::load(r#"[{"type":"receive","stateMutability":"payable"}]"#.as_bytes()).is_ok());
+	}
+}
@@ -0,0 +1,339 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Solidity compiler artifacts.
+
+use std::{borrow::Cow, collections::BTreeMap, io};
+
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+
+pub use crate::bytecode::{LinkOffset, LinkReferences};
+use crate::{errors, Bytecode, Contract, Error};
+
+/// Compiled contract as emitted by solc, Hardhat, Foundry or Truffle.
+///
+/// The following layouts are recognized:
+/// - a bare ABI array,
+/// - Hardhat and Truffle artifacts with `bytecode` and `deployedBytecode` hex strings,
+/// - Foundry artifacts and solc standard JSON contract output, where bytecode is an object
+///   (`{ "object": ..., "linkReferences": ... }`), either at the top level or under `evm`,
+/// - a single entry of `solc --combined-json` output (`abi`, `bin` and `bin-runtime`).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Artifact {
+	/// Contract name, if the artifact records it.
+	pub contract_name: Option<String>,
+	/// Contract ABI.
+	pub abi: Contract,
+	/// Creation bytecode, possibly containing library placeholders.
+	pub bytecode: Option<Bytecode>,
+	/// Runtime bytecode, possibly containing library placeholders.
+	pub deployed_bytecode: Option<Bytecode>,
+	/// Library link references of the creation bytecode.
+	pub link_references: LinkReferences,
+	/// Library link references of the runtime bytecode.
+	pub deployed_link_references: LinkReferences,
+}
+
+impl<'a> Deserialize<'a> for Artifact {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'a>,
+	{
+		let value = Value::deserialize(deserializer)?;
+		Artifact::from_value(value).map_err(serde::de::Error::custom)
+	}
+}
+
+impl Artifact {
+	/// Loads a single contract artifact from json.
+	///
+	/// `solc --combined-json` output is accepted as long as it contains exactly one contract,
+	/// use [`Artifact::load_all`] otherwise.
+	pub fn load<T: io::Read>(reader: T) -> errors::Result<Self> {
+		let value: Value = serde_json::from_reader(reader)?;
+		if is_combined_json(&value) {
+			let mut artifacts = combined_json(value)?;
+			if artifacts.len() != 1 {
+				let names = artifacts.keys().cloned().collect::<Vec<_>>().join(", ");
+				return Err(Error::Other(Cow::Owned(format!(
+					"Expected a single contract in combined json output, found: {names}"
+				))));
+			}
+			let (_, artifact) = artifacts.pop_first().expect("exactly one artifact; qed");
+			return Ok(artifact);
+		}
+
+		Self::from_value(value)
+	}
+
+	/// Loads all contract artifacts from json, keyed by contract name.
+	///
+	/// For `solc --combined-json` output the keys are the `path:Name` identifiers used by the
+	/// compiler, any other layout yields a single artifact keyed by its contract name (or an
+	/// empty string when the artifact does not record it).
+	pub fn load_all<T: io::Read>(reader: T) -> errors::Result<BTreeMap<String, Self>> {
+		let value: Value = serde_json::from_reader(reader)?;
+		if is_combined_json(&value) {
+			return combined_json(value);
+		}
+
+		let artifact = Self::from_value(value)?;
+		Ok(BTreeMap::from([(artifact.contract_name.clone().unwrap_or_default(), artifact)]))
+	}
+
+	fn from_value(value: Value) -> errors::Result<Self> {
+		let mut object = match value {
+			Value::Array(_) => return Ok(Artifact { abi: serde_json::from_value(value)?, ..Default::default() }),
+			Value::Object(object) => object,
+			_ => return Err(Error::Other(Cow::Borrowed("Expected an ABI array or a compiler artifact object"))),
+		};
+
+		let abi = match object.remove("abi") {
+			// `solc --combined-json` before 0.8.10 emits the ABI as a json string.
+			Some(Value::String(abi)) => serde_json::from_str(&abi)?,
+			Some(abi) => serde_json::from_value(abi)?,
+			None => return Err(Error::Other(Cow::Borrowed("Compiler artifact has no `abi` field"))),
+		};
+
+		let contract_name = match object.remove("contractName") {
+			Some(Value::String(name)) => Some(name),
+			_ => None,
+		};
+
+		let mut evm = match object.remove("evm") {
+			Some(Value::Object(evm)) => evm,
+			_ => Map::new(),
+		};
+
+		let (bytecode, link_references) = bytecode_object(
+			object.remove("bytecode").or_else(|| evm.remove("bytecode")).or_else(|| object.remove("bin")),
+			object.remove("linkReferences"),
+		)?;
+		let (deployed_bytecode, deployed_link_references) = bytecode_object(
+			object
+				.remove("deployedBytecode")
+				.or_else(|| evm.remove("deployedBytecode"))
+				.or_else(|| object.remove("bin-runtime")),
+			object.remove("deployedLinkReferences"),
+		)?;
+
+		Ok(Artifact { contract_name, abi, bytecode, deployed_bytecode, link_references, deployed_link_references })
+	}
+}
+
+impl From<Artifact> for Contract {
+	fn from(artifact: Artifact) -> Self {
+		artifact.abi
+	}
+}
+
+fn is_combined_json(value: &Value) -> bool {
+	matches!(value.get("contracts"), Some(Value::Object(_))) && value.get("abi").is_none()
+}
+
+fn combined_json(value: Value) -> errors::Result<BTreeMap<String, Artifact>> {
+	let contracts = match value {
+		Value::Object(mut object) => object.remove("contracts"),
+		_ => None,
+	};
+
+	match contracts {
+		Some(Value::Object(contracts)) => contracts
+			.into_iter()
+			.map(|(id, contract)| {
+				let mut artifact = Artifact::from_value(contract)?;
+				if artifact.contract_name.is_none() {
+					let name = id.rsplit(':').next().unwrap_or(&id);
+					artifact.contract_name = Some(name.to_owned());
+				}
+				Ok((id, artifact))
+			})
+			.collect(),
+		_ => Err(Error::Other(Cow::Borrowed("Combined json output has no `contracts` field"))),
+	}
+}
+
+/// Extracts bytecode and its link references from either a plain hex string or a
+/// `{ "object": ..., "linkReferences": ... }` bytecode object.
+fn bytecode_object(
+	bytecode: Option<Value>,
+	link_references: Option<Value>,
+) -> errors::Result<(Option<Bytecode>, LinkReferences)> {
+	let (object, link_references) = match bytecode {
+		Some(Value::String(object)) => (object, link_references),
+		Some(Value::Object(mut bytecode)) => {
+			let object = match bytecode.remove("object") {
+				Some(Value::String(object)) => object,
+				_ => String::new(),
+			};
+			(object, bytecode.remove("linkReferences").or(link_references))
+		}
+		Some(Value::Null) | None => (String::new(), link_references),
+		Some(_) => return Err(Error::Other(Cow::Borrowed("Invalid bytecode in compiler artifact"))),
+	};
+
+	let link_references = match link_references {
+		Some(Value::Null) | None => LinkReferences::new(),
+		Some(link_references) => serde_json::from_value(link_references)?,
+	};
+
+	let object = object.strip_prefix("0x").unwrap_or(&object);
+	let bytecode = match object.is_empty() {
+		true => None,
+		false => Some(Bytecode::from_hex(object)?.with_link_references(&link_references)),
+	};
+
+	Ok((bytecode, link_references))
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::BTreeMap;
+
+	use super::{Artifact, LinkOffset};
+
+	const ABI: &str = r#"[
+		{
+			"type": "function",
+			"name": "foo",
+			"inputs": [{ "name": "a", "type": "bool" }],
+			"outputs": []
+		}
+	]"#;
+
+	#[test]
+	fn bare_abi() {
+		let artifact = Artifact::load(ABI.as_bytes()).unwrap();
+
+		assert!(artifact.abi.function("foo").is_ok());
+		assert_eq!(artifact.contract_name, None);
+		assert_eq!(artifact.bytecode, None);
+		assert_eq!(artifact.deployed_bytecode, None);
+	}
+
+	#[test]
+	fn hardhat() {
+		let json = format!(
+			r#"{{
+				"_format": "hh-sol-artifact-1",
+				"contractName": "Foo",
+				"sourceName": "contracts/Foo.sol",
+				"abi": {ABI},
+				"bytecode": "0x6080__$b0a8cd1e68d9a4e5cc3ad6e4a8bd8d2b07$__6000",
+				"deployedBytecode": "0x6000",
+				"linkReferences": {{
+					"contracts/Lib.sol": {{
+						"Lib": [{{ "length": 20, "start": 2 }}]
+					}}
+				}},
+				"deployedLinkReferences": {{}}
+			}}"#
+		);
+
+		let artifact = Artifact::load(json.as_bytes()).unwrap();
+
+		assert!(artifact.abi.function("foo").is_ok());
+		assert_eq!(artifact.contract_name.as_deref(), Some("Foo"));
+		let bytecode = artifact.bytecode.as_ref().unwrap();
+		assert_eq!(bytecode.to_string(), "6080__$b0a8cd1e68d9a4e5cc3ad6e4a8bd8d2b07$__6000");
+		assert_eq!(bytecode.unlinked_libraries(), vec!["contracts/Lib.sol:Lib".to_owned()]);
+		assert_eq!(artifact.deployed_bytecode.as_ref().unwrap().code(), [0x60, 0x00]);
+		assert_eq!(
+			artifact.link_references,
+			BTreeMap::from([(
+				"contracts/Lib.sol".to_owned(),
+				BTreeMap::from([("Lib".to_owned(), vec![LinkOffset { start: 2, length: 20 }])])
+			)])
+		);
+		assert!(artifact.deployed_link_references.is_empty());
+	}
+
+	#[test]
+	fn hardhat_interface() {
+		let json = format!(r#"{{ "contractName": "IFoo", "abi": {ABI}, "bytecode": "0x", "deployedBytecode": "0x" }}"#);
+
+		let artifact = Artifact::load(json.as_bytes()).unwrap();
+
+		assert_eq!(artifact.bytecode, None);
+		assert_eq!(artifact.deployed_bytecode, None);
+	}
+
+	#[test]
+	fn foundry() {
+		let json = format!(
+			r#"{{
+				"abi": {ABI},
+				"bytecode": {{
+					"object": "0x60806040",
+					"sourceMap": "",
+					"linkReferences": {{ "src/Lib.sol": {{ "Lib": [{{ "start": 1, "length": 20 }}] }} }}
+				}},
+				"deployedBytecode": {{ "object": "0x6080", "sourceMap": "", "linkReferences": {{}} }},
+				"metadata": {{ "compiler": {{ "version": "0.8.19" }} }}
+			}}"#
+		);
+
+		let artifact = Artifact::load(json.as_bytes()).unwrap();
+
+		assert_eq!(artifact.bytecode.as_ref().unwrap().len(), 4);
+		assert_eq!(artifact.deployed_bytecode.as_ref().unwrap().code(), [0x60, 0x80]);
+		assert_eq!(artifact.link_references["src/Lib.sol"]["Lib"], vec![LinkOffset { start: 1, length: 20 }]);
+	}
+
+	#[test]
+	fn solc_standard_json() {
+		let json = format!(
+			r#"{{
+				"abi": {ABI},
+				"evm": {{
+					"bytecode": {{ "object": "60806040", "linkReferences": {{}} }},
+					"deployedBytecode": {{ "object": "6080", "linkReferences": {{}} }}
+				}}
+			}}"#
+		);
+
+		let artifact = Artifact::load(json.as_bytes()).unwrap();
+
+		assert_eq!(artifact.bytecode.unwrap().code(), [0x60, 0x80, 0x60, 0x40]);
+		assert_eq!(artifact.deployed_bytecode.unwrap().code(), [0x60, 0x80]);
+	}
+
+	#[test]
+	fn solc_combined_json() {
+		let json = format!(
+			r#"{{
+				"contracts": {{
+					"Foo.sol:Foo": {{ "abi": {ABI}, "bin": "60806040", "bin-runtime": "6080" }},
+					"Foo.sol:Bar": {{ "abi": {}, "bin": "", "bin-runtime": "" }}
+				}},
+				"version": "0.8.19+commit.7dd6d404.Linux.g++"
+			}}"#,
+			serde_json::to_string(ABI).unwrap()
+		);
+
+		assert!(Artifact::load(json.as_bytes()).is_err());
+
+		let artifacts = Artifact::load_all(json.as_bytes()).unwrap();
+		assert_eq!(artifacts.keys().collect::<Vec<_>>(), vec!["Foo.sol:Bar", "Foo.sol:Foo"]);
+
+		let foo = &artifacts["Foo.sol:Foo"];
+		assert_eq!(foo.contract_name.as_deref(), Some("Foo"));
+		assert_eq!(foo.bytecode.as_ref().unwrap().code(), [0x60, 0x80, 0x60, 0x40]);
+		assert_eq!(foo.deployed_bytecode.as_ref().unwrap().code(), [0x60, 0x80]);
+
+		let bar = &artifacts["Foo.sol:Bar"];
+		assert!(bar.abi.function("foo").is_ok());
+		assert_eq!(bar.bytecode, None);
+	}
+
+	#[test]
+	fn missing_abi() {
+		assert!(Artifact::load(r#"{ "bytecode": "0x00" }"#.as_bytes()).is_err());
+	}
+}
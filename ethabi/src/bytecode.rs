@@ -0,0 +1,398 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Contract bytecode with library placeholders.
+
+use alloc::collections::BTreeMap;
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{no_std_prelude::Cow, Address, Bytes, Error, Result};
+
+/// Length of a library placeholder in hex characters.
+const PLACEHOLDER_LEN: usize = 40;
+
+/// Length of a library address in bytes.
+const ADDRESS_LEN: usize = 20;
+
+/// Library link references of a bytecode object, keyed by source file and library name.
+pub type LinkReferences = BTreeMap<String, BTreeMap<String, Vec<LinkOffset>>>;
+
+/// Location of a library address placeholder inside a bytecode object.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinkOffset {
+	/// Byte offset of the placeholder.
+	pub start: usize,
+	/// Byte length of the placeholder, always 20 for an address.
+	pub length: usize,
+}
+
+/// Library reference encoded in a placeholder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlaceholderKind {
+	/// `__Lib.sol:Lib__________________________` placeholder emitted by solc before 0.5.0,
+	/// containing the library name truncated to 36 characters.
+	Name(String),
+	/// `__$<hash>$__` placeholder, containing the first 17 bytes of the Keccak-256 hash of the
+	/// fully qualified library name.
+	Hash([u8; 17]),
+}
+
+/// Unresolved library placeholder in contract bytecode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placeholder {
+	/// Byte offset of the placeholder.
+	pub offset: usize,
+	/// Library reference encoded in the placeholder.
+	pub kind: PlaceholderKind,
+	/// Fully qualified library name (`path:Name`), if known from the link references.
+	pub library: Option<String>,
+}
+
+impl Placeholder {
+	/// Returns true if this placeholder refers to the library `name`.
+	///
+	/// `name` may either be fully qualified (`path:Name`) or a bare library name. Bare names
+	/// only match hashed placeholders whose fully qualified name is known.
+	fn matches(&self, name: &str) -> bool {
+		if let Some(ref library) = self.library {
+			if library == name || unqualified(library) == name {
+				return true;
+			}
+		}
+
+		match self.kind {
+			PlaceholderKind::Name(ref placeholder) => {
+				placeholder == name
+					|| unqualified(placeholder) == name
+					|| (name.len() > PLACEHOLDER_LEN - 4
+						&& name.get(..PLACEHOLDER_LEN - 4) == Some(placeholder.as_str()))
+			}
+			PlaceholderKind::Hash(ref hash) => hash[..] == Keccak256::digest(name.as_bytes())[..17],
+		}
+	}
+
+	/// Human readable name of the referenced library.
+	fn display_name(&self) -> String {
+		match (&self.library, &self.kind) {
+			(Some(library), _) => library.clone(),
+			(None, PlaceholderKind::Name(name)) => name.clone(),
+			(None, PlaceholderKind::Hash(hash)) => format!("${}$", hex::encode(hash)),
+		}
+	}
+}
+
+impl fmt::Display for Placeholder {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.kind {
+			PlaceholderKind::Name(ref name) => write!(f, "__{name:_<width$}", width = PLACEHOLDER_LEN - 2),
+			PlaceholderKind::Hash(ref hash) => write!(f, "__${}$__", hex::encode(hash)),
+		}
+	}
+}
+
+/// Contract bytecode which may contain library placeholders that have to be linked before
+/// deployment.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Bytecode {
+	/// Bytecode with zeroed placeholders.
+	code: Bytes,
+	/// Unresolved placeholders, ordered by offset.
+	placeholders: Vec<Placeholder>,
+}
+
+impl Bytecode {
+	/// Parses hex encoded bytecode, with or without `0x` prefix, that may contain library
+	/// placeholders.
+	pub fn from_hex(hex: &str) -> Result<Self> {
+		let mut rest = hex.strip_prefix("0x").unwrap_or(hex);
+		let mut code = Vec::with_capacity(rest.len() / 2);
+		let mut placeholders = Vec::new();
+
+		while let Some(pos) = rest.find("__") {
+			if pos % 2 != 0 {
+				return Err(Error::InvalidData);
+			}
+			// non-ASCII input may not split at character boundaries
+			let placeholder = rest.get(pos..pos + PLACEHOLDER_LEN).ok_or(Error::InvalidData)?;
+
+			code.extend(decode_hex(&rest[..pos])?);
+			let kind = parse_placeholder(placeholder)?;
+			placeholders.push(Placeholder { offset: code.len(), kind, library: None });
+			code.extend([0u8; ADDRESS_LEN]);
+			rest = &rest[pos + PLACEHOLDER_LEN..];
+		}
+		code.extend(decode_hex(rest)?);
+
+		Ok(Bytecode { code, placeholders })
+	}
+
+	/// Records the fully qualified library names of placeholders from compiler link references,
+	/// so that hashed placeholders can be linked by bare library name.
+	pub fn with_link_references(mut self, references: &LinkReferences) -> Self {
+		for (file, libraries) in references {
+			for (library, offsets) in libraries {
+				for offset in offsets {
+					if let Some(placeholder) = self.placeholders.iter_mut().find(|p| p.offset == offset.start) {
+						placeholder.library = Some(format!("{file}:{library}"));
+					}
+				}
+			}
+		}
+		self
+	}
+
+	/// Replaces all placeholders of library `name` with its deployed `address`.
+	///
+	/// `name` may be either the fully qualified library name (`path:Name`) or the bare library
+	/// name. Fails if the bytecode does not reference the library.
+	pub fn link(&mut self, name: &str, address: Address) -> Result<()> {
+		let code = &mut self.code;
+		let before = self.placeholders.len();
+		self.placeholders.retain(|placeholder| {
+			if !placeholder.matches(name) {
+				return true;
+			}
+			code[placeholder.offset..placeholder.offset + ADDRESS_LEN].copy_from_slice(address.as_bytes());
+			false
+		});
+
+		if self.placeholders.len() == before {
+			return Err(Error::InvalidName(name.to_owned()));
+		}
+
+		Ok(())
+	}
+
+	/// Returns true if the bytecode has no unresolved library placeholders.
+	pub fn is_linked(&self) -> bool {
+		self.placeholders.is_empty()
+	}
+
+	/// Returns unresolved library placeholders.
+	pub fn placeholders(&self) -> &[Placeholder] {
+		&self.placeholders
+	}
+
+	/// Returns names of libraries which are not linked yet.
+	pub fn unlinked_libraries(&self) -> Vec<String> {
+		let mut names: Vec<_> = self.placeholders.iter().map(Placeholder::display_name).collect();
+		names.sort();
+		names.dedup();
+		names
+	}
+
+	/// Returns the bytecode, with unresolved placeholders zeroed.
+	pub fn code(&self) -> &[u8] {
+		&self.code
+	}
+
+	/// Returns the rest of `data` if it starts with this bytecode, accepting any address in place
+	/// of unresolved placeholders.
+	pub fn strip_prefix<'a>(&self, data: &'a [u8]) -> Option<&'a [u8]> {
+		let (prefix, rest) = (data.get(..self.code.len())?, &data[self.code.len()..]);
+		let mut start = 0;
+		for placeholder in &self.placeholders {
+			if prefix[start..placeholder.offset] != self.code[start..placeholder.offset] {
+				return None;
+			}
+			start = placeholder.offset + ADDRESS_LEN;
+		}
+		(prefix[start..] == self.code[start..]).then_some(rest)
+	}
+
+	/// Returns length of the bytecode in bytes.
+	pub fn len(&self) -> usize {
+		self.code.len()
+	}
+
+	/// Returns true if the bytecode is empty.
+	pub fn is_empty(&self) -> bool {
+		self.code.is_empty()
+	}
+
+	/// Returns the deployable bytecode, failing if any library is not linked.
+	pub fn into_bytes(self) -> Result<Bytes> {
+		if !self.is_linked() {
+			return Err(Error::Other(Cow::Owned(format!(
+				"Bytecode contains unlinked libraries: {}",
+				self.unlinked_libraries().join(", ")
+			))));
+		}
+		Ok(self.code)
+	}
+}
+
+impl From<Bytes> for Bytecode {
+	fn from(code: Bytes) -> Self {
+		Bytecode { code, placeholders: Vec::new() }
+	}
+}
+
+impl FromStr for Bytecode {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self> {
+		Bytecode::from_hex(s)
+	}
+}
+
+impl fmt::Display for Bytecode {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let mut start = 0;
+		for placeholder in &self.placeholders {
+			write!(f, "{}{placeholder}", hex::encode(&self.code[start..placeholder.offset]))?;
+			start = placeholder.offset + ADDRESS_LEN;
+		}
+		write!(f, "{}", hex::encode(&self.code[start..]))
+	}
+}
+
+fn decode_hex(hex: &str) -> Result<Bytes> {
+	hex::decode(hex).map_err(|_| Error::InvalidData)
+}
+
+fn parse_placeholder(placeholder: &str) -> Result<PlaceholderKind> {
+	match placeholder.strip_prefix("__$").and_then(|p| p.strip_suffix("$__")) {
+		Some(hash) => {
+			let mut result = [0u8; 17];
+			hex::decode_to_slice(hash, &mut result).map_err(|_| Error::InvalidData)?;
+			Ok(PlaceholderKind::Hash(result))
+		}
+		None => {
+			let name = placeholder[2..].trim_end_matches('_');
+			if name.is_empty() {
+				return Err(Error::InvalidData);
+			}
+			Ok(PlaceholderKind::Name(name.to_owned()))
+		}
+	}
+}
+
+fn unqualified(name: &str) -> &str {
+	name.rsplit(':').next().unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::collections::BTreeMap;
+
+	use hex_literal::hex;
+
+	use super::{Bytecode, LinkOffset, PlaceholderKind};
+	#[cfg(not(feature = "std"))]
+	use crate::no_std_prelude::*;
+	use crate::Address;
+
+	// keccak256("contracts/Lib.sol:Lib")[..17]
+	const HASHED: &str = "6000__$6cf167dfb7c5c94c9fb5276b5691085b47$__6001";
+	const NAMED: &str = "6000__contracts/Lib.sol:Lib_________________6001";
+
+	fn address() -> Address {
+		hex!("1111111111111111111111111111111111111111").into()
+	}
+
+	#[test]
+	fn parse_without_placeholders() {
+		let bytecode: Bytecode = "0x60806040".parse().unwrap();
+
+		assert!(bytecode.is_linked());
+		assert_eq!(bytecode.code(), hex!("60806040"));
+		assert_eq!(bytecode.into_bytes().unwrap(), hex!("60806040").to_vec());
+	}
+
+	#[test]
+	fn parse_invalid() {
+		assert!(Bytecode::from_hex("6080zz").is_err());
+		assert!(Bytecode::from_hex("608__foo").is_err());
+		assert!(Bytecode::from_hex("6080__$1234$__").is_err());
+	}
+
+	#[test]
+	fn parse_and_link_non_ascii() {
+		assert!(Bytecode::from_hex(&format!("__{}é", "a".repeat(37))).is_err());
+		assert!(Bytecode::from_hex("60__é").is_err());
+
+		let mut bytecode = Bytecode::from_hex(NAMED).unwrap();
+		assert!(bytecode.link(&format!("{}é", "a".repeat(35)), address()).is_err());
+		assert!(!bytecode.is_linked());
+	}
+
+	#[test]
+	fn link_hashed_placeholder() {
+		let mut bytecode = Bytecode::from_hex(HASHED).unwrap();
+
+		assert!(!bytecode.is_linked());
+		assert_eq!(bytecode.len(), 24);
+		assert_eq!(bytecode.placeholders()[0].offset, 2);
+		assert_eq!(bytecode.to_string(), HASHED);
+		assert_eq!(bytecode.unlinked_libraries(), vec!["$6cf167dfb7c5c94c9fb5276b5691085b47$".to_owned()]);
+		assert!(bytecode.clone().into_bytes().is_err());
+
+		// bare names cannot be resolved without link references
+		assert!(bytecode.link("Lib", address()).is_err());
+		bytecode.link("contracts/Lib.sol:Lib", address()).unwrap();
+
+		assert!(bytecode.is_linked());
+		assert_eq!(bytecode.into_bytes().unwrap(), hex!("60001111111111111111111111111111111111111111 6001").to_vec());
+	}
+
+	#[test]
+	fn link_with_link_references() {
+		let references = BTreeMap::from([(
+			"contracts/Lib.sol".to_owned(),
+			BTreeMap::from([("Lib".to_owned(), vec![LinkOffset { start: 2, length: 20 }])]),
+		)]);
+		let mut bytecode = Bytecode::from_hex(HASHED).unwrap().with_link_references(&references);
+
+		assert_eq!(bytecode.unlinked_libraries(), vec!["contracts/Lib.sol:Lib".to_owned()]);
+		assert!(bytecode.link("Other", address()).is_err());
+		bytecode.link("Lib", address()).unwrap();
+		assert!(bytecode.is_linked());
+	}
+
+	#[test]
+	fn link_named_placeholder() {
+		let mut bytecode = Bytecode::from_hex(NAMED).unwrap();
+
+		assert_eq!(bytecode.placeholders()[0].kind, PlaceholderKind::Name("contracts/Lib.sol:Lib".to_owned()));
+		assert_eq!(bytecode.to_string(), NAMED);
+
+		bytecode.link("Lib", address()).unwrap();
+		assert_eq!(bytecode.into_bytes().unwrap(), hex!("60001111111111111111111111111111111111111111 6001").to_vec());
+	}
+
+	#[test]
+	fn strip_prefix_with_placeholders() {
+		let bytecode = Bytecode::from_hex(NAMED).unwrap();
+		let deployed = hex!("60001111111111111111111111111111111111111111 6001 2a");
+		assert_eq!(bytecode.strip_prefix(&deployed), Some(&hex!("2a")[..]));
+		assert_eq!(bytecode.strip_prefix(&deployed[..24]), Some(&[][..]));
+		assert_eq!(bytecode.strip_prefix(&deployed[..23]), None);
+		assert_eq!(bytecode.strip_prefix(&hex!("60011111111111111111111111111111111111111111 6001")), None);
+		assert_eq!(bytecode.strip_prefix(&hex!("60001111111111111111111111111111111111111111 6002")), None);
+	}
+
+	#[test]
+	fn link_multiple_occurrences() {
+		let hex = format!("{NAMED}{NAMED}");
+		let mut bytecode = Bytecode::from_hex(&hex).unwrap();
+
+		assert_eq!(bytecode.placeholders().len(), 2);
+		assert_eq!(bytecode.unlinked_libraries(), vec!["contracts/Lib.sol:Lib".to_owned()]);
+		bytecode.link("contracts/Lib.sol:Lib", address()).unwrap();
+		assert!(bytecode.is_linked());
+		assert_eq!(bytecode.len(), 48);
+	}
+}
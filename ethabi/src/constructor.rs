@@ -13,7 +13,7 @@ use serde::{Deserialize, Serialize};
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-use crate::{encode, Bytes, Error, Param, ParamType, Result, Token};
+use crate::{decode, encode, Bytecode, Bytes, Error, Param, ParamType, Result, Token};
 
 /// Contract constructor specification.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -30,13 +30,160 @@ impl Constructor {
 	}
 
 	/// Prepares ABI constructor call with given input params.
-	pub fn encode_input(&self, code: Bytes, tokens: &[Token]) -> Result<Bytes> {
+	///
+	/// Fails if `code` still contains unlinked library placeholders.
+	pub fn encode_input<C: Into<Bytecode>>(&self, code: C, tokens: &[Token]) -> Result<Bytes> {
 		let params = self.param_types();
 
 		if Token::types_check(tokens, &params) {
+			let code = code.into().into_bytes()?;
 			Ok(code.into_iter().chain(encode(tokens)).collect())
 		} else {
 			Err(Error::InvalidData)
 		}
 	}
+
+	/// Parses the constructor arguments of deployment `data` created from `creation_code`.
+	///
+	/// Libraries linked at deployment may have any address in place of the unlinked placeholders
+	/// of `creation_code`.
+	pub fn decode_input<C: Into<Bytecode>>(&self, creation_code: C, data: &[u8]) -> Result<Vec<Token>> {
+		let args = creation_code.into().strip_prefix(data).ok_or(Error::InvalidData)?;
+		decode(&self.param_types(), args)
+	}
+
+	/// Parses the constructor arguments of deployment `data` without knowing the creation code.
+	///
+	/// Arguments are appended to the creation code, so this looks for the shortest suffix of
+	/// `data` that is a canonical encoding of the constructor params.
+	pub fn decode_input_suffix(&self, data: &[u8]) -> Result<Vec<Token>> {
+		let params = self.param_types();
+		if params.is_empty() {
+			return Ok(vec![]);
+		}
+
+		let head_len = params.iter().map(head_len).sum::<usize>();
+		if params.iter().all(|param| !param.is_dynamic()) {
+			let start = data.len().checked_sub(head_len).ok_or(Error::InvalidData)?;
+			return decode(&params, &data[start..]);
+		}
+
+		(head_len..=data.len())
+			.step_by(32)
+			.find_map(|len| {
+				let args = &data[data.len() - len..];
+				decode(&params, args).ok().filter(|tokens| encode(tokens) == args)
+			})
+			.ok_or(Error::InvalidData)
+	}
+}
+
+/// Returns the length of the param encoding in the head of an encoded params list.
+fn head_len(param: &ParamType) -> usize {
+	match param {
+		_ if param.is_dynamic() => 32,
+		ParamType::FixedArray(inner, len) => head_len(inner) * len,
+		ParamType::Tuple(inner) => inner.iter().map(head_len).sum(),
+		_ => 32,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use hex_literal::hex;
+
+	#[cfg(not(feature = "std"))]
+	use crate::no_std_prelude::*;
+	use crate::{Bytecode, Constructor, Param, ParamType, Token};
+
+	fn constructor() -> Constructor {
+		Constructor {
+			inputs: vec![Param {
+				name: "a".to_owned(),
+				kind: ParamType::Bool,
+				internal_type: None,
+				components: vec![],
+			}],
+		}
+	}
+
+	fn dynamic_constructor() -> Constructor {
+		Constructor {
+			inputs: vec![
+				Param { name: "a".to_owned(), kind: ParamType::String, internal_type: None, components: vec![] },
+				Param {
+					name: "b".to_owned(),
+					kind: ParamType::FixedArray(Box::new(ParamType::Uint(256)), 2),
+					internal_type: None,
+					components: vec![],
+				},
+			],
+		}
+	}
+
+	#[test]
+	fn encode_input() {
+		let encoded = constructor().encode_input(hex!("6080").to_vec(), &[Token::Bool(true)]).unwrap();
+		let expected = hex!("60800000000000000000000000000000000000000000000000000000000000000001").to_vec();
+		assert_eq!(encoded, expected);
+	}
+
+	#[test]
+	fn encode_input_requires_linked_bytecode() {
+		let mut code = Bytecode::from_hex("6080__$6cf167dfb7c5c94c9fb5276b5691085b47$__").unwrap();
+		assert!(constructor().encode_input(code.clone(), &[Token::Bool(true)]).is_err());
+
+		code.link("contracts/Lib.sol:Lib", [0x11u8; 20].into()).unwrap();
+		let encoded = constructor().encode_input(code, &[Token::Bool(true)]).unwrap();
+		let expected = hex!(
+			"
+			60801111111111111111111111111111111111111111
+			0000000000000000000000000000000000000000000000000000000000000001
+			"
+		)
+		.to_vec();
+		assert_eq!(encoded, expected);
+	}
+
+	#[test]
+	fn decode_input() {
+		let code = hex!("6080604052").to_vec();
+		let tokens = [
+			Token::String("gavofyork".to_owned()),
+			Token::FixedArray(vec![Token::Uint(1.into()), Token::Uint(2.into())]),
+		];
+		let data = dynamic_constructor().encode_input(code.clone(), &tokens).unwrap();
+
+		assert_eq!(dynamic_constructor().decode_input(code, &data).unwrap(), tokens);
+		assert!(dynamic_constructor().decode_input(hex!("6080604053").to_vec(), &data).is_err());
+	}
+
+	#[test]
+	fn decode_input_with_unlinked_libraries() {
+		let code = Bytecode::from_hex("6080__$6cf167dfb7c5c94c9fb5276b5691085b47$__").unwrap();
+		let mut linked = code.clone();
+		linked.link("contracts/Lib.sol:Lib", [0x11u8; 20].into()).unwrap();
+		let data = constructor().encode_input(linked, &[Token::Bool(true)]).unwrap();
+
+		assert_eq!(constructor().decode_input(code, &data).unwrap(), [Token::Bool(true)]);
+		assert!(constructor().decode_input(hex!("6081").to_vec(), &data).is_err());
+	}
+
+	#[test]
+	fn decode_input_suffix() {
+		let code = hex!("6080604052").to_vec();
+
+		let tokens = [Token::Bool(true)];
+		let data = constructor().encode_input(code.clone(), &tokens).unwrap();
+		assert_eq!(constructor().decode_input_suffix(&data).unwrap(), tokens);
+
+		let tokens = [
+			Token::String("gavofyork".to_owned()),
+			Token::FixedArray(vec![Token::Uint(1.into()), Token::Uint(2.into())]),
+		];
+		let data = dynamic_constructor().encode_input(code.clone(), &tokens).unwrap();
+		assert_eq!(dynamic_constructor().decode_input_suffix(&data).unwrap(), tokens);
+
+		assert!(dynamic_constructor().decode_input_suffix(&code).is_err());
+	}
 }
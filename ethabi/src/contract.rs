@@ -25,6 +25,8 @@ use crate::no_std_prelude::*;
 #[cfg(feature = "serde")]
 use crate::operation::Operation;
 use crate::{error::Error as AbiError, errors, Constructor, Error, Event, Function};
+#[cfg(feature = "full-serde")]
+use crate::{loader, AbiDocument, LoadReport};
 
 /// API building calls to contracts ABI.
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -70,26 +72,7 @@ impl<'a> Visitor<'a> for ContractVisitor {
 	{
 		let mut result = Contract::default();
 		while let Some(operation) = seq.next_element()? {
-			match operation {
-				Operation::Constructor(constructor) => {
-					result.constructor = Some(constructor);
-				}
-				Operation::Function(func) => {
-					result.functions.entry(func.name.clone()).or_default().push(func);
-				}
-				Operation::Event(event) => {
-					result.events.entry(event.name.clone()).or_default().push(event);
-				}
-				Operation::Error(error) => {
-					result.errors.entry(error.name.clone()).or_default().push(error);
-				}
-				Operation::Fallback => {
-					result.fallback = true;
-				}
-				Operation::Receive => {
-					result.receive = true;
-				}
-			}
+			result.insert(operation);
 		}
 
 		Ok(result)
@@ -168,6 +151,46 @@ impl Contract {
 		serde_json::from_reader(reader).map_err(From::from)
 	}
 
+	/// Loads contract from json written by old compilers or by hand, applying the defaults of the
+	/// Solidity ABI spec and skipping items which cannot be loaded.
+	///
+	/// Everything questionable about the ABI is collected in the returned report.
+	#[cfg(feature = "full-serde")]
+	pub fn load_lenient<T: io::Read>(reader: T) -> errors::Result<(Self, LoadReport)> {
+		loader::load(AbiDocument::load(reader)?, false)
+	}
+
+	/// Loads contract from json, failing on anything [`Contract::load_lenient`] would report and on
+	/// legacy `constant` and `payable` fields without `stateMutability`, which are ambiguous.
+	#[cfg(feature = "full-serde")]
+	pub fn load_strict<T: io::Read>(reader: T) -> errors::Result<Self> {
+		loader::load(AbiDocument::load(reader)?, true).map(|(contract, _)| contract)
+	}
+
+	#[cfg(feature = "serde")]
+	pub(crate) fn insert(&mut self, operation: Operation) {
+		match operation {
+			Operation::Constructor(constructor) => {
+				self.constructor = Some(constructor);
+			}
+			Operation::Function(func) => {
+				self.functions.entry(func.name.clone()).or_default().push(func);
+			}
+			Operation::Event(event) => {
+				self.events.entry(event.name.clone()).or_default().push(event);
+			}
+			Operation::Error(error) => {
+				self.errors.entry(error.name.clone()).or_default().push(error);
+			}
+			Operation::Fallback => {
+				self.fallback = true;
+			}
+			Operation::Receive => {
+				self.receive = true;
+			}
+		}
+	}
+
 	/// Creates constructor call builder.
 	pub fn constructor(&self) -> Option<&Constructor> {
 		self.constructor.as_ref()
@@ -205,17 +228,17 @@ impl Contract {
 	}
 
 	/// Iterate over all functions of the contract in arbitrary order.
-	pub fn functions(&self) -> Functions {
+	pub fn functions(&self) -> Functions<'_> {
 		Functions(self.functions.values().flatten())
 	}
 
 	/// Iterate over all events of the contract in arbitrary order.
-	pub fn events(&self) -> Events {
+	pub fn events(&self) -> Events<'_> {
 		Events(self.events.values().flatten())
 	}
 
 	/// Iterate over all errors of the contract in arbitrary order.
-	pub fn errors(&self) -> AbiErrors {
+	pub fn errors(&self) -> AbiErrors<'_> {
 		AbiErrors(self.errors.values().flatten())
 	}
 }
@@ -306,7 +329,12 @@ mod test {
 			deserialized,
 			Contract {
 				constructor: Some(Constructor {
-					inputs: vec![Param { name: "a".to_string(), kind: ParamType::Address, internal_type: None }]
+					inputs: vec![Param {
+						name: "a".to_string(),
+						kind: ParamType::Address,
+						internal_type: None,
+						components: vec![]
+					}]
 				}),
 				functions: BTreeMap::new(),
 				events: BTreeMap::new(),
@@ -363,11 +391,13 @@ mod test {
 								name: "a".to_string(),
 								kind: ParamType::Address,
 								internal_type: None,
+								components: vec![],
 							}],
 							outputs: vec![Param {
 								name: "res".to_string(),
 								kind: ParamType::Address,
 								internal_type: None,
+								components: vec![],
 							}],
 							constant: None,
 							state_mutability: Default::default(),
@@ -438,11 +468,13 @@ mod test {
 								name: "a".to_string(),
 								kind: ParamType::Address,
 								internal_type: None,
+								components: vec![],
 							}],
 							outputs: vec![Param {
 								name: "res".to_string(),
 								kind: ParamType::Address,
 								internal_type: None,
+								components: vec![],
 							}],
 							constant: None,
 							state_mutability: Default::default(),
@@ -512,6 +544,7 @@ mod test {
 								name: "a".to_string(),
 								kind: ParamType::Address,
 								indexed: false,
+								components: vec![],
 							}],
 							anonymous: false,
 						}]
@@ -520,7 +553,12 @@ mod test {
 						"bar".to_string(),
 						vec![Event {
 							name: "bar".to_string(),
-							inputs: vec![EventParam { name: "a".to_string(), kind: ParamType::Address, indexed: true }],
+							inputs: vec![EventParam {
+								name: "a".to_string(),
+								kind: ParamType::Address,
+								indexed: true,
+								components: vec![]
+							}],
 							anonymous: false,
 						}]
 					),
@@ -580,12 +618,18 @@ mod test {
 								name: "a".to_string(),
 								kind: ParamType::Address,
 								indexed: false,
+								components: vec![],
 							}],
 							anonymous: false,
 						},
 						Event {
 							name: "foo".to_string(),
-							inputs: vec![EventParam { name: "a".to_string(), kind: ParamType::Address, indexed: true }],
+							inputs: vec![EventParam {
+								name: "a".to_string(),
+								kind: ParamType::Address,
+								indexed: true,
+								components: vec![]
+							}],
 							anonymous: false,
 						},
 					]
@@ -652,8 +696,14 @@ mod test {
 									name: "available".to_string(),
 									kind: ParamType::Uint(256),
 									internal_type: None,
+									components: vec![],
 								},
-								Param { name: "required".to_string(), kind: ParamType::Address, internal_type: None }
+								Param {
+									name: "required".to_string(),
+									kind: ParamType::Address,
+									internal_type: None,
+									components: vec![]
+								}
 							],
 						}]
 					),
@@ -662,8 +712,18 @@ mod test {
 						vec![AbiError {
 							name: "bar".to_string(),
 							inputs: vec![
-								Param { name: "a".to_string(), kind: ParamType::Uint(256), internal_type: None },
-								Param { name: "b".to_string(), kind: ParamType::Address, internal_type: None }
+								Param {
+									name: "a".to_string(),
+									kind: ParamType::Uint(256),
+									internal_type: None,
+									components: vec![]
+								},
+								Param {
+									name: "b".to_string(),
+									kind: ParamType::Address,
+									internal_type: None,
+									components: vec![]
+								}
 							],
 						}]
 					),
@@ -724,13 +784,24 @@ mod test {
 								name: "a".to_string(),
 								kind: ParamType::Uint(256),
 								internal_type: None,
+								components: vec![],
 							}],
 						},
 						AbiError {
 							name: "foo".to_string(),
 							inputs: vec![
-								Param { name: "a".to_string(), kind: ParamType::Uint(256), internal_type: None },
-								Param { name: "b".to_string(), kind: ParamType::Address, internal_type: None }
+								Param {
+									name: "a".to_string(),
+									kind: ParamType::Uint(256),
+									internal_type: None,
+									components: vec![]
+								},
+								Param {
+									name: "b".to_string(),
+									kind: ParamType::Address,
+									internal_type: None,
+									components: vec![]
+								}
 							],
 						},
 					]
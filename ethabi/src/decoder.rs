@@ -93,7 +93,7 @@ fn peek_32_bytes(data: &[u8], offset: usize) -> Result<Word, Error> {
 }
 
 fn round_up_nearest_multiple(value: usize, padding: usize) -> usize {
-	(value + padding - 1) / padding * padding
+	value.div_ceil(padding) * padding
 }
 
 fn take_bytes(data: &[u8], offset: usize, len: usize, validate: bool) -> Result<Vec<u8>, Error> {
@@ -656,6 +656,7 @@ ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff
 						name: "c".to_string(),
 						kind: Array(Box::new(Tuple(vec![Uint(256), Uint(256)]))),
 						internal_type: None,
+						components: vec![],
 					},
 					Param {
 						name: "d".to_string(),
@@ -664,6 +665,7 @@ ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff
 							Array(Box::new(Tuple(vec![Uint(256), Array(Box::new(ParamType::String))]))),
 						]))),
 						internal_type: None,
+						components: vec![],
 					},
 				],
 				outputs: vec![],
@@ -696,11 +698,12 @@ ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff
 			Function {
 				name: "f".to_string(),
 				inputs: vec![
-					Param { name: "i".to_string(), kind: Uint(256), internal_type: None },
+					Param { name: "i".to_string(), kind: Uint(256), internal_type: None, components: vec![] },
 					Param {
 						name: "p".to_string(),
 						kind: FixedArray(Box::new(ParamType::String), 2),
 						internal_type: None,
+						components: vec![],
 					},
 				],
 				outputs: vec![],
@@ -10,11 +10,11 @@
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-use crate::{util::pad_u32, Bytes, Token, Word};
+use crate::{util::pad_u32, Bytes, Error, ParamType, Token, Word};
 
 fn pad_bytes_len(bytes: &[u8]) -> u32 {
 	// "+ 1" because len is also appended
-	((bytes.len() + 31) / 32) as u32 + 1
+	bytes.len().div_ceil(32) as u32 + 1
 }
 
 fn pad_bytes_append(data: &mut Vec<Word>, bytes: &[u8]) {
@@ -23,11 +23,11 @@ fn pad_bytes_append(data: &mut Vec<Word>, bytes: &[u8]) {
 }
 
 fn fixed_bytes_len(bytes: &[u8]) -> u32 {
-	((bytes.len() + 31) / 32) as u32
+	bytes.len().div_ceil(32) as u32
 }
 
 fn fixed_bytes_append(result: &mut Vec<Word>, bytes: &[u8]) {
-	let len = (bytes.len() + 31) / 32;
+	let len = bytes.len().div_ceil(32);
 	for i in 0..len {
 		let mut padded = [0u8; 32];
 
@@ -130,13 +130,13 @@ fn encode_head_tail_append(acc: &mut Vec<Word>, mediates: &[Mediate]) {
 	mediates.iter().for_each(|m| m.tail_append(acc));
 }
 
-fn mediate_token(token: &Token) -> Mediate {
+fn mediate_token(token: &Token) -> Mediate<'_> {
 	match token {
 		Token::Address(_) => Mediate::Raw(1, token),
 		Token::Bytes(bytes) => Mediate::Prefixed(pad_bytes_len(bytes), token),
 		Token::String(s) => Mediate::Prefixed(pad_bytes_len(s.as_bytes()), token),
 		Token::FixedBytes(bytes) => Mediate::Raw(fixed_bytes_len(bytes), token),
-		Token::Int(_) | Token::Uint(_) | Token::Bool(_) => Mediate::Raw(1, token),
+		Token::Int(_) | Token::Uint(_) | Token::Bool(_) | Token::HashedTopic(_) => Mediate::Raw(1, token),
 		Token::Array(ref tokens) => {
 			let mediates = tokens.iter().map(mediate_token).collect();
 
@@ -166,6 +166,8 @@ fn encode_token_append(data: &mut Vec<Word>, token: &Token) {
 		Token::FixedBytes(ref bytes) => fixed_bytes_append(data, bytes),
 		Token::Int(int) => data.push(int.into()),
 		Token::Uint(uint) => data.push(uint.into()),
+		// the topic word it was decoded from, typed encoding rejects it
+		Token::HashedTopic(hash) => data.push(hash.0),
 		Token::Bool(b) => {
 			let mut value = [0u8; 32];
 			if b {
@@ -177,13 +179,101 @@ fn encode_token_append(data: &mut Vec<Word>, token: &Token) {
 	};
 }
 
+/// Encodes tokens the way Solidity's `abi.encodePacked` does.
+///
+/// Types are required since packed integers only take as many bytes as their type. Value types
+/// are encoded without padding, `bytes` and `string` without length, and elements of arrays are
+/// padded to 32 bytes. Tuples and arrays of dynamic or nested types are not supported.
+pub fn encode_packed(types: &[ParamType], tokens: &[Token]) -> Result<Bytes, Error> {
+	if types.len() != tokens.len() {
+		return Err(Error::InvalidData);
+	}
+
+	let mut result = Vec::new();
+	for (kind, token) in types.iter().zip(tokens) {
+		encode_packed_token(&mut result, kind, token)?;
+	}
+	Ok(result)
+}
+
+fn encode_packed_token(result: &mut Bytes, kind: &ParamType, token: &Token) -> Result<(), Error> {
+	if !token.type_check(kind) {
+		return Err(Error::InvalidData);
+	}
+
+	match (kind, token) {
+		(_, Token::Address(address)) => result.extend_from_slice(address.as_bytes()),
+		(ParamType::Uint(size), Token::Uint(value)) | (ParamType::Int(size), Token::Int(value)) => {
+			check_int_range(kind, *value)?;
+			let mut word = [0u8; 32];
+			value.to_big_endian(&mut word);
+			result.extend_from_slice(&word[32 - size / 8..]);
+		}
+		(_, Token::Bool(b)) => result.push(*b as u8),
+		(ParamType::FixedBytes(size), Token::FixedBytes(bytes)) => {
+			result.extend_from_slice(bytes);
+			result.resize(result.len() + size - bytes.len(), 0);
+		}
+		(_, Token::Bytes(bytes)) => result.extend_from_slice(bytes),
+		(_, Token::String(s)) => result.extend_from_slice(s.as_bytes()),
+		(ParamType::Array(inner), Token::Array(tokens))
+		| (ParamType::FixedArray(inner, _), Token::FixedArray(tokens)) => {
+			if inner.is_dynamic()
+				|| matches!(**inner, ParamType::Array(_) | ParamType::FixedArray(..) | ParamType::Tuple(_))
+			{
+				return Err(Error::InvalidData);
+			}
+			for token in tokens {
+				if let Token::Uint(value) | Token::Int(value) = *token {
+					check_int_range(inner, value)?;
+				}
+				result.extend(encode(core::slice::from_ref(token)));
+			}
+		}
+		_ => return Err(Error::InvalidData),
+	}
+	Ok(())
+}
+
+/// Checks that an integer token fits into the size of its type.
+pub(crate) fn check_int_range(kind: &ParamType, value: crate::Uint) -> Result<(), Error> {
+	let fits = match *kind {
+		ParamType::Uint(size) => value.bits() <= size,
+		// two's complement, all bits above the sign bit have to match it
+		ParamType::Int(size) if value.bit(255) => (!value).bits() < size,
+		ParamType::Int(size) => value.bits() < size,
+		_ => true,
+	};
+	match fits {
+		true => Ok(()),
+		false => Err(Error::InvalidData),
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use hex_literal::hex;
 
 	#[cfg(not(feature = "std"))]
 	use crate::no_std_prelude::*;
-	use crate::{encode, util::pad_u32, Token};
+	use crate::{encode, encode_packed, util::pad_u32, ParamType, Token};
+
+	#[test]
+	fn encode_hashed_topic() {
+		let encoded = encode(&[Token::HashedTopic([0x11u8; 32].into()), Token::Bool(true)]);
+		let expected = hex!(
+			"
+			1111111111111111111111111111111111111111111111111111111111111111
+			0000000000000000000000000000000000000000000000000000000000000001
+		"
+		);
+		assert_eq!(encoded, expected);
+	}
+
+	#[test]
+	fn encode_packed_hashed_topic() {
+		assert!(encode_packed(&[ParamType::String], &[Token::HashedTopic([0x11u8; 32].into())]).is_err());
+	}
 
 	#[test]
 	fn encode_address() {
@@ -596,7 +686,7 @@ mod tests {
 
 	#[test]
 	fn comprehensive_test2() {
-		let encoded = encode(&vec![
+		let encoded = encode(&[
 			Token::Int(1.into()),
 			Token::String("gavofyork".to_owned()),
 			Token::Int(2.into()),
@@ -873,4 +963,54 @@ mod tests {
 		.to_vec();
 		assert_eq!(encoded, expected);
 	}
+
+	#[test]
+	fn encode_packed_value_types() {
+		let types = [ParamType::Int(16), ParamType::Bytes, ParamType::Uint(8), ParamType::String, ParamType::Address];
+		let tokens = [
+			Token::Int(!crate::Int::zero()),
+			Token::Bytes(vec![0x42]),
+			Token::Uint(3.into()),
+			Token::String("Hello, world!".to_owned()),
+			Token::Address([0x11u8; 20].into()),
+		];
+		let encoded = encode_packed(&types, &tokens).unwrap();
+		let expected = hex!("ffff420348656c6c6f2c20776f726c64211111111111111111111111111111111111111111");
+		assert_eq!(encoded, expected);
+	}
+
+	#[test]
+	fn encode_packed_fixed_bytes_and_bool() {
+		let types = [ParamType::FixedBytes(4), ParamType::Bool];
+		let tokens = [Token::FixedBytes(vec![0x12, 0x34]), Token::Bool(true)];
+		assert_eq!(encode_packed(&types, &tokens).unwrap(), hex!("1234000001"));
+	}
+
+	#[test]
+	fn encode_packed_array() {
+		let types = [ParamType::Array(Box::new(ParamType::Uint(8)))];
+		let tokens = [Token::Array(vec![Token::Uint(1.into()), Token::Uint(2.into())])];
+		let expected = hex!(
+			"
+			0000000000000000000000000000000000000000000000000000000000000001
+			0000000000000000000000000000000000000000000000000000000000000002
+			"
+		);
+		assert_eq!(encode_packed(&types, &tokens).unwrap(), expected);
+	}
+
+	#[test]
+	fn encode_packed_rejects_invalid_input() {
+		// value out of range
+		assert!(encode_packed(&[ParamType::Uint(8)], &[Token::Uint(256.into())]).is_err());
+		assert!(encode_packed(&[ParamType::Int(8)], &[Token::Int(128.into())]).is_err());
+		// arrays of dynamic types
+		let types = [ParamType::Array(Box::new(ParamType::String))];
+		assert!(encode_packed(&types, &[Token::Array(vec![Token::String("a".to_owned())])]).is_err());
+		// tuples
+		let types = [ParamType::Tuple(vec![ParamType::Bool])];
+		assert!(encode_packed(&types, &[Token::Tuple(vec![Token::Bool(true)])]).is_err());
+		// mismatched types
+		assert!(encode_packed(&[ParamType::Bool], &[Token::Uint(1.into())]).is_err());
+	}
 }
@@ -56,19 +56,18 @@ impl Event {
 	}
 
 	/// Creates topic filter
+	///
+	/// `raw.topicN` filters the `N`th indexed param. Regular events can have up to three indexed params
+	/// after the event signature, anonymous events up to four.
 	pub fn filter(&self, raw: RawTopicFilter) -> Result<TopicFilter> {
 		fn convert_token(token: Token, kind: &ParamType) -> Result<Hash> {
+			if let Token::HashedTopic(hash) = token {
+				return if is_hashed_topic(kind) { Ok(hash) } else { Err(Error::InvalidData) };
+			}
 			if !token.type_check(kind) {
 				return Err(Error::InvalidData);
 			}
-			let encoded = encode(&[token]);
-			if encoded.len() == 32 {
-				let mut data = [0u8; 32];
-				data.copy_from_slice(&encoded);
-				Ok(data.into())
-			} else {
-				Ok(Hash::from_slice(Keccak256::digest(&encoded).as_slice()))
-			}
+			Ok(topic_for(kind, &token))
 		}
 
 		fn convert_topic(topic: Topic<Token>, kind: Option<&ParamType>) -> Result<Topic<Hash>> {
@@ -90,17 +89,26 @@ impl Event {
 		}
 
 		let kinds: Vec<_> = self.indexed_params(true).into_iter().map(|param| param.kind).collect();
+		// anonymous events use all four topics for indexed params
+		let max_indexed = if self.anonymous { 4 } else { 3 };
+		if kinds.len() > max_indexed {
+			return Err(Error::InvalidData);
+		}
+
 		let result = if self.anonymous {
 			TopicFilter {
-				topic0: convert_topic(raw.topic0, kinds.get(0))?,
+				topic0: convert_topic(raw.topic0, kinds.first())?,
 				topic1: convert_topic(raw.topic1, kinds.get(1))?,
 				topic2: convert_topic(raw.topic2, kinds.get(2))?,
-				topic3: Topic::Any,
+				topic3: convert_topic(raw.topic3, kinds.get(3))?,
 			}
 		} else {
+			if !raw.topic3.is_any() {
+				return Err(Error::InvalidData);
+			}
 			TopicFilter {
 				topic0: Topic::This(self.signature()),
-				topic1: convert_topic(raw.topic0, kinds.get(0))?,
+				topic1: convert_topic(raw.topic0, kinds.first())?,
 				topic2: convert_topic(raw.topic1, kinds.get(1))?,
 				topic3: convert_topic(raw.topic2, kinds.get(2))?,
 			}
@@ -114,13 +122,9 @@ impl Event {
 	// these indexed param types according to
 	// https://solidity.readthedocs.io/en/develop/abi-spec.html#encoding-of-indexed-event-parameters
 	fn convert_topic_param_type(&self, kind: &ParamType) -> ParamType {
-		match kind {
-			ParamType::String
-			| ParamType::Bytes
-			| ParamType::Array(_)
-			| ParamType::FixedArray(_, _)
-			| ParamType::Tuple(_) => ParamType::FixedBytes(32),
-			_ => kind.clone(),
+		match is_hashed_topic(kind) {
+			true => ParamType::FixedBytes(32),
+			false => kind.clone(),
 		}
 	}
 
@@ -136,7 +140,7 @@ impl Event {
 			0
 		} else {
 			// verify
-			let event_signature = topics.get(0).ok_or(Error::InvalidData)?;
+			let event_signature = topics.first().ok_or(Error::InvalidData)?;
 			if event_signature != &self.signature() {
 				return Err(Error::InvalidData);
 			}
@@ -155,13 +159,18 @@ impl Event {
 			return Err(Error::InvalidData);
 		}
 
-		let topics_named_tokens = topic_params.into_iter().map(|p| p.name).zip(topic_tokens.into_iter());
+		let topic_tokens = topic_params.iter().zip(topic_tokens).map(|(param, token)| match token {
+			Token::FixedBytes(hash) if is_hashed_topic(&param.kind) => Token::HashedTopic(Hash::from_slice(&hash)),
+			token => token,
+		});
+
+		let topics_named_tokens = topic_params.iter().map(|p| p.name.clone()).zip(topic_tokens);
 
 		let data_types = data_params.iter().map(|p| p.kind.clone()).collect::<Vec<ParamType>>();
 
 		let data_tokens = decode(&data_types, &data)?;
 
-		let data_named_tokens = data_params.into_iter().map(|p| p.name).zip(data_tokens.into_iter());
+		let data_named_tokens = data_params.into_iter().map(|p| p.name).zip(data_tokens);
 
 		let named_tokens = topics_named_tokens.chain(data_named_tokens).collect::<BTreeMap<String, Token>>();
 
@@ -188,6 +197,63 @@ impl Event {
 	}
 }
 
+/// Returns true if indexed params of the given type are stored in topics as a hash.
+pub(crate) fn is_hashed_topic(kind: &ParamType) -> bool {
+	matches!(
+		*kind,
+		ParamType::String | ParamType::Bytes | ParamType::Array(_) | ParamType::FixedArray(..) | ParamType::Tuple(_)
+	)
+}
+
+/// Returns the log topic of an indexed event param.
+///
+/// Value types are stored in the topic padded to 32 bytes. `string` and `bytes` are stored as the
+/// Keccak-256 hash of their contents, arrays and structs as the hash of the in-place encoding of
+/// their elements, in which every element is padded to a multiple of 32 bytes without any length
+/// or offset. A [`Token::HashedTopic`] is returned as is.
+///
+/// `token` is expected to be a hashed topic or type check against `kind`.
+pub fn topic_for(kind: &ParamType, token: &Token) -> Hash {
+	if let Token::HashedTopic(hash) = *token {
+		return hash;
+	}
+
+	if !is_hashed_topic(kind) {
+		let mut topic = [0u8; 32];
+		let encoded = encode(core::slice::from_ref(token));
+		let len = encoded.len().min(32);
+		topic[..len].copy_from_slice(&encoded[..len]);
+		return topic.into();
+	}
+
+	let data = match *token {
+		Token::String(ref s) => s.as_bytes().to_vec(),
+		Token::Bytes(ref bytes) => bytes.clone(),
+		_ => {
+			let mut data = Vec::new();
+			in_place_append(&mut data, token);
+			data
+		}
+	};
+	Hash::from_slice(&Keccak256::digest(data))
+}
+
+fn in_place_append(data: &mut Vec<u8>, token: &Token) {
+	match *token {
+		Token::String(ref s) => padded_append(data, s.as_bytes()),
+		Token::Bytes(ref bytes) => padded_append(data, bytes),
+		Token::Array(ref tokens) | Token::FixedArray(ref tokens) | Token::Tuple(ref tokens) => {
+			tokens.iter().for_each(|token| in_place_append(data, token))
+		}
+		_ => data.extend(encode(core::slice::from_ref(token))),
+	}
+}
+
+fn padded_append(data: &mut Vec<u8>, bytes: &[u8]) {
+	data.extend_from_slice(bytes);
+	data.resize(data.len() + (32 - bytes.len() % 32) % 32, 0);
+}
+
 #[cfg(test)]
 mod tests {
 	use hex_literal::hex;
@@ -198,28 +264,128 @@ mod tests {
 		log::{Log, RawLog},
 		signature::long_signature,
 		token::Token,
-		Event, EventParam, LogParam, ParamType,
+		topic_for, Event, EventParam, LogParam, ParamType, RawTopicFilter, Topic, TopicFilter,
 	};
 
+	#[test]
+	fn test_topic_for() {
+		let address = Token::Address([0x11u8; 20].into());
+		assert_eq!(
+			topic_for(&ParamType::Address, &address),
+			hex!("0000000000000000000000001111111111111111111111111111111111111111").into()
+		);
+		assert_eq!(
+			topic_for(&ParamType::String, &Token::String("hello".to_owned())),
+			hex!("1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8").into()
+		);
+		assert_eq!(
+			topic_for(&ParamType::Bytes, &Token::Bytes(b"hello".to_vec())),
+			hex!("1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8").into()
+		);
+		// keccak256(abi.encode(1, 2)), without offset and length
+		assert_eq!(
+			topic_for(
+				&ParamType::Array(Box::new(ParamType::Uint(256))),
+				&Token::Array(vec![Token::Uint(1.into()), Token::Uint(2.into())])
+			),
+			hex!("e90b7bceb6e7df5418fb78d8ee546e97c83a08bbccc01a0644d599ccd2a7c2e0").into()
+		);
+		// dynamic members are padded in place
+		assert_eq!(
+			topic_for(
+				&ParamType::Tuple(vec![ParamType::String, ParamType::Uint(256)]),
+				&Token::Tuple(vec![Token::String("a".to_owned()), Token::Uint(1.into())])
+			),
+			hex!("8f1decdd0aa22b721a442da0685384aed2d6884c2b835360d7f57ac46d881ae7").into()
+		);
+		let hash = Token::HashedTopic([0x22u8; 32].into());
+		assert_eq!(topic_for(&ParamType::String, &hash), [0x22u8; 32].into());
+	}
+
+	#[test]
+	fn test_filter_hashed_topic() {
+		let event = Event {
+			name: "foo".to_owned(),
+			inputs: vec![EventParam {
+				name: "a".to_owned(),
+				kind: ParamType::String,
+				indexed: true,
+				components: vec![],
+			}],
+			anonymous: false,
+		};
+		let raw = RawTopicFilter { topic0: Topic::This(Token::String("hello".to_owned())), ..Default::default() };
+		let filter = event.filter(raw).unwrap();
+		assert_eq!(
+			filter.topic1,
+			Topic::This(hex!("1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8").into())
+		);
+
+		let raw = RawTopicFilter { topic0: Topic::This(Token::HashedTopic([0x22u8; 32].into())), ..Default::default() };
+		assert_eq!(event.filter(raw).unwrap().topic1, Topic::This([0x22u8; 32].into()));
+
+		let event = indexed_addresses(1, false);
+		let raw = RawTopicFilter { topic0: Topic::This(Token::HashedTopic([0x22u8; 32].into())), ..Default::default() };
+		assert!(event.filter(raw).is_err());
+	}
+
+	fn indexed_addresses(count: usize, anonymous: bool) -> Event {
+		let inputs = (0..count)
+			.map(|i| EventParam { name: format!("a{i}"), kind: ParamType::Address, indexed: true, components: vec![] })
+			.collect();
+		Event { name: "foo".to_owned(), inputs, anonymous }
+	}
+
+	#[test]
+	fn test_filter_anonymous_four_topics() {
+		let event = indexed_addresses(4, true);
+		let raw = RawTopicFilter {
+			topic0: Topic::This(Token::Address([0x11u8; 20].into())),
+			topic3: Topic::This(Token::Address([0x44u8; 20].into())),
+			..Default::default()
+		};
+
+		let expected = TopicFilter {
+			topic0: Topic::This(hex!("0000000000000000000000001111111111111111111111111111111111111111").into()),
+			topic1: Topic::Any,
+			topic2: Topic::Any,
+			topic3: Topic::This(hex!("0000000000000000000000004444444444444444444444444444444444444444").into()),
+		};
+		assert_eq!(event.filter(raw).unwrap(), expected);
+	}
+
+	#[test]
+	fn test_filter_indexed_count() {
+		assert!(indexed_addresses(4, false).filter(RawTopicFilter::default()).is_err());
+		assert!(indexed_addresses(5, true).filter(RawTopicFilter::default()).is_err());
+
+		let raw = RawTopicFilter { topic3: Topic::This(Token::Address([0x44u8; 20].into())), ..Default::default() };
+		assert!(indexed_addresses(3, false).filter(raw).is_err());
+		let raw = RawTopicFilter { topic3: Topic::This(Token::Address([0x44u8; 20].into())), ..Default::default() };
+		assert!(indexed_addresses(3, true).filter(raw).is_err());
+	}
+
 	#[test]
 	fn test_decoding_event() {
 		let event = Event {
 			name: "foo".to_owned(),
 			inputs: vec![
-				EventParam { name: "a".to_owned(), kind: ParamType::Int(256), indexed: false },
-				EventParam { name: "b".to_owned(), kind: ParamType::Int(256), indexed: true },
-				EventParam { name: "c".to_owned(), kind: ParamType::Address, indexed: false },
-				EventParam { name: "d".to_owned(), kind: ParamType::Address, indexed: true },
-				EventParam { name: "e".to_owned(), kind: ParamType::String, indexed: true },
+				EventParam { name: "a".to_owned(), kind: ParamType::Int(256), indexed: false, components: vec![] },
+				EventParam { name: "b".to_owned(), kind: ParamType::Int(256), indexed: true, components: vec![] },
+				EventParam { name: "c".to_owned(), kind: ParamType::Address, indexed: false, components: vec![] },
+				EventParam { name: "d".to_owned(), kind: ParamType::Address, indexed: true, components: vec![] },
+				EventParam { name: "e".to_owned(), kind: ParamType::String, indexed: true, components: vec![] },
 				EventParam {
 					name: "f".to_owned(),
 					kind: ParamType::Array(Box::new(ParamType::Int(256))),
 					indexed: true,
+					components: vec![],
 				},
 				EventParam {
 					name: "g".to_owned(),
 					kind: ParamType::FixedArray(Box::new(ParamType::Address), 5),
 					indexed: true,
+					components: vec![],
 				},
 			],
 			anonymous: false,
@@ -265,19 +431,19 @@ mod tests {
 					("d", Token::Address(hex!("1111111111111111111111111111111111111111").into())),
 					(
 						"e",
-						Token::FixedBytes(
+						Token::HashedTopic(
 							hex!("00000000000000000aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa").into()
 						)
 					),
 					(
 						"f",
-						Token::FixedBytes(
+						Token::HashedTopic(
 							hex!("00000000000000000bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb").into()
 						)
 					),
 					(
 						"g",
-						Token::FixedBytes(
+						Token::HashedTopic(
 							hex!("00000000000000000ccccccccccccccccccccccccccccccccccccccccccccccc").into()
 						)
 					),
@@ -299,8 +465,9 @@ mod tests {
 					name: "tuple".into(),
 					kind: ParamType::Tuple(vec![ParamType::Address, ParamType::Address]),
 					indexed: false,
+					components: vec![],
 				},
-				EventParam { name: "addr".into(), kind: ParamType::Address, indexed: true },
+				EventParam { name: "addr".into(), kind: ParamType::Address, indexed: true, components: vec![] },
 			],
 			anonymous: false,
 		};
@@ -20,9 +20,9 @@ use serde::{
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-use crate::ParamType;
 #[cfg(feature = "serde")]
-use crate::{param_type::Writer, TupleParam};
+use crate::param_type::Writer;
+use crate::{ParamType, TupleParam};
 
 /// Event param specification.
 #[derive(Debug, Clone, PartialEq)]
@@ -33,6 +33,21 @@ pub struct EventParam {
 	pub kind: ParamType,
 	/// Indexed flag. If true, param is used to build block bloom.
 	pub indexed: bool,
+	/// Components of a tuple param or of the tuples of an array param, empty if unknown.
+	pub components: Vec<TupleParam>,
+}
+
+impl EventParam {
+	/// Creates an event param without components.
+	pub fn new<S: Into<String>>(name: S, kind: ParamType, indexed: bool) -> Self {
+		EventParam { name: name.into(), kind, indexed, components: Vec::new() }
+	}
+
+	/// Sets the components of a tuple param or of the tuples of an array param.
+	pub fn with_components(mut self, components: Vec<TupleParam>) -> Self {
+		self.components = components;
+		self
+	}
 }
 
 #[cfg(feature = "serde")]
@@ -97,9 +112,9 @@ impl<'a> Visitor<'a> for EventParamVisitor {
 		}
 		let name = name.ok_or_else(|| Error::missing_field("name"))?;
 		let mut kind = kind.ok_or_else(|| Error::missing_field("kind"))?;
-		crate::param::set_tuple_components(&mut kind, components)?;
+		let components = crate::param::set_tuple_components(&mut kind, components)?;
 		let indexed = indexed.unwrap_or(false);
-		Ok(EventParam { name, kind, indexed })
+		Ok(EventParam { name, kind, indexed, components })
 	}
 }
 
@@ -113,10 +128,7 @@ impl Serialize for EventParam {
 		map.serialize_entry("name", &self.name)?;
 		map.serialize_entry("type", &Writer::write_for_abi(&self.kind, false))?;
 		map.serialize_entry("indexed", &self.indexed)?;
-		if let Some(inner_tuple) = crate::param::inner_tuple(&self.kind) {
-			map.serialize_key("components")?;
-			map.serialize_value(&crate::param::SerializeableParamVec(inner_tuple))?;
-		}
+		crate::param::serialize_components(&mut map, &self.kind, &self.components)?;
 		map.end()
 	}
 }
@@ -125,7 +137,11 @@ impl Serialize for EventParam {
 mod tests {
 	#[cfg(not(feature = "std"))]
 	use crate::no_std_prelude::*;
-	use crate::{tests::assert_json_eq, EventParam, ParamType};
+	use crate::{tests::assert_json_eq, EventParam, ParamType, TupleParam};
+
+	fn component(kind: ParamType, components: Vec<TupleParam>) -> TupleParam {
+		TupleParam { name: None, kind, internal_type: None, components }
+	}
 
 	#[test]
 	fn event_param_deserialization() {
@@ -137,7 +153,10 @@ mod tests {
 
 		let deserialized: EventParam = serde_json::from_str(s).unwrap();
 
-		assert_eq!(deserialized, EventParam { name: "foo".to_owned(), kind: ParamType::Address, indexed: true });
+		assert_eq!(
+			deserialized,
+			EventParam { name: "foo".to_owned(), kind: ParamType::Address, indexed: true, components: vec![] }
+		);
 
 		assert_json_eq(s, serde_json::to_string(&deserialized).unwrap().as_str());
 	}
@@ -171,6 +190,10 @@ mod tests {
 				name: "foo".to_owned(),
 				kind: ParamType::Tuple(vec![ParamType::Uint(48), ParamType::Tuple(vec![ParamType::Address])]),
 				indexed: true,
+				components: vec![
+					component(ParamType::Uint(48), vec![]),
+					component(ParamType::Tuple(vec![ParamType::Address]), vec![component(ParamType::Address, vec![])]),
+				],
 			}
 		);
 
@@ -237,6 +260,43 @@ mod tests {
 					ParamType::Uint(256),
 				]),
 				indexed: false,
+				components: vec![
+					component(ParamType::Uint(256), vec![]),
+					component(ParamType::Address, vec![]),
+					component(
+						ParamType::Tuple(vec![ParamType::Address, ParamType::Address]),
+						vec![component(ParamType::Address, vec![]), component(ParamType::Address, vec![])]
+					),
+					component(ParamType::Uint(256), vec![]),
+					component(
+						ParamType::Array(Box::new(ParamType::Tuple(vec![
+							ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Address, ParamType::Bytes]))),
+							ParamType::Array(Box::new(ParamType::Tuple(vec![
+								ParamType::Address,
+								ParamType::Uint(256)
+							]))),
+							ParamType::Uint(256),
+						]))),
+						vec![
+							component(
+								ParamType::Array(Box::new(ParamType::Tuple(vec![
+									ParamType::Address,
+									ParamType::Bytes
+								]))),
+								vec![component(ParamType::Address, vec![]), component(ParamType::Bytes, vec![])]
+							),
+							component(
+								ParamType::Array(Box::new(ParamType::Tuple(vec![
+									ParamType::Address,
+									ParamType::Uint(256)
+								]))),
+								vec![component(ParamType::Address, vec![]), component(ParamType::Uint(256), vec![])]
+							),
+							component(ParamType::Uint(256), vec![]),
+						]
+					),
+					component(ParamType::Uint(256), vec![]),
+				],
 			}
 		);
 
@@ -7,15 +7,20 @@
 // except according to those terms.
 
 use core::ops;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
 
 #[cfg(feature = "serde")]
 use serde::{Serialize, Serializer};
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-use crate::{Hash, Token};
+use crate::{Hash, RawLog, Token};
 
 /// Raw topic filter.
+///
+/// Topics filter indexed params in order. The fourth topic is only available for anonymous events,
+/// which do not use the first topic for the event signature.
 #[derive(Debug, PartialEq, Default)]
 pub struct RawTopicFilter {
 	/// Topic.
@@ -24,6 +29,8 @@ pub struct RawTopicFilter {
 	pub topic1: Topic<Token>,
 	/// Topic.
 	pub topic2: Topic<Token>,
+	/// Topic, anonymous events only.
+	pub topic3: Topic<Token>,
 }
 
 /// Topic filter.
@@ -39,6 +46,17 @@ pub struct TopicFilter {
 	pub topic3: Topic<Hash>,
 }
 
+impl TopicFilter {
+	/// Returns true if the topics of `log` satisfy the filter.
+	pub fn matches(&self, log: &RawLog) -> bool {
+		self.topics().iter().enumerate().all(|(i, topic)| topic.matches(log.topics.get(i)))
+	}
+
+	fn topics(&self) -> [&Topic<Hash>; 4] {
+		[&self.topic0, &self.topic1, &self.topic2, &self.topic3]
+	}
+}
+
 #[cfg(feature = "serde")]
 impl Serialize for TopicFilter {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -81,6 +99,22 @@ impl<T> Topic<T> {
 			Topic::This(_) | Topic::OneOf(_) => false,
 		}
 	}
+
+	/// Returns true if `topic` is accepted, `None` standing for a topic missing from the log.
+	///
+	/// An empty `Topic::OneOf` accepts everything, like an empty array in a JSON-RPC filter.
+	pub fn matches(&self, topic: Option<&T>) -> bool
+	where
+		T: PartialEq,
+	{
+		match (self, topic) {
+			(Topic::Any, _) => true,
+			(Topic::OneOf(topics), _) if topics.is_empty() => true,
+			(_, None) => false,
+			(Topic::This(expected), Some(topic)) => expected == topic,
+			(Topic::OneOf(topics), Some(topic)) => topics.contains(topic),
+		}
+	}
 }
 
 impl<T> From<Option<T>> for Topic<T> {
@@ -115,7 +149,7 @@ impl<T> From<Topic<T>> for Vec<T> {
 }
 
 #[cfg(feature = "serde")]
-impl Serialize for Topic<Hash> {
+impl<T: Serialize> Serialize for Topic<T> {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 	where
 		S: Serializer,
@@ -123,7 +157,7 @@ impl Serialize for Topic<Hash> {
 		match *self {
 			Topic::Any => Option::<()>::None.serialize(serializer),
 			Topic::OneOf(ref vec) => vec.serialize(serializer),
-			Topic::This(ref hash) => hash.serialize(serializer),
+			Topic::This(ref topic) => topic.serialize(serializer),
 		}
 	}
 }
@@ -145,17 +179,116 @@ impl<T> ops::Index<usize> for Topic<T> {
 	}
 }
 
+/// Topics accepted at one position, `None` accepting any topic.
+#[cfg(feature = "std")]
+type TopicSet = Option<HashSet<Hash>>;
+
+#[cfg(feature = "std")]
+fn topic_set(topic: &Topic<Hash>) -> TopicSet {
+	match *topic {
+		Topic::Any => None,
+		Topic::This(hash) => Some(core::iter::once(hash).collect()),
+		Topic::OneOf(ref hashes) if hashes.is_empty() => None,
+		Topic::OneOf(ref hashes) => Some(hashes.iter().copied().collect()),
+	}
+}
+
+/// Set of topic filters, each identified by a key, evaluated against logs at once.
+///
+/// Filters are indexed by their first topic, so matching a log only checks the filters
+/// expecting its event signature and the filters accepting any first topic.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct LogMatcher<K> {
+	filters: Vec<(K, [TopicSet; 3])>,
+	by_topic0: HashMap<Hash, Vec<usize>>,
+	any_topic0: Vec<usize>,
+}
+
+#[cfg(feature = "std")]
+impl<K> Default for LogMatcher<K> {
+	fn default() -> Self {
+		LogMatcher { filters: Vec::new(), by_topic0: HashMap::new(), any_topic0: Vec::new() }
+	}
+}
+
+#[cfg(feature = "std")]
+impl<K> LogMatcher<K> {
+	/// Creates an empty matcher.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds `filter` identified by `key`.
+	pub fn insert(&mut self, key: K, filter: &TopicFilter) {
+		let index = self.filters.len();
+		match topic_set(&filter.topic0) {
+			Some(hashes) => hashes.into_iter().for_each(|hash| self.by_topic0.entry(hash).or_default().push(index)),
+			None => self.any_topic0.push(index),
+		}
+		let rest = [topic_set(&filter.topic1), topic_set(&filter.topic2), topic_set(&filter.topic3)];
+		self.filters.push((key, rest));
+	}
+
+	/// Returns the keys of all filters matching `log`, in insertion order.
+	pub fn matches(&self, log: &RawLog) -> Vec<&K> {
+		let mut candidates = log
+			.topics
+			.first()
+			.and_then(|topic0| self.by_topic0.get(topic0))
+			.into_iter()
+			.flatten()
+			.chain(&self.any_topic0)
+			.copied()
+			.collect::<Vec<_>>();
+		candidates.sort_unstable();
+
+		candidates
+			.into_iter()
+			.map(|index| &self.filters[index])
+			.filter(|(_, rest)| {
+				rest.iter().enumerate().all(|(i, set)| match (set, log.topics.get(i + 1)) {
+					(None, _) => true,
+					(Some(set), Some(topic)) => set.contains(topic),
+					(Some(_), None) => false,
+				})
+			})
+			.map(|(key, _)| key)
+			.collect()
+	}
+
+	/// Returns the number of filters.
+	pub fn len(&self) -> usize {
+		self.filters.len()
+	}
+
+	/// Returns true if there are no filters.
+	pub fn is_empty(&self) -> bool {
+		self.filters.is_empty()
+	}
+}
+
+#[cfg(feature = "std")]
+impl<K> FromIterator<(K, TopicFilter)> for LogMatcher<K> {
+	fn from_iter<I: IntoIterator<Item = (K, TopicFilter)>>(iter: I) -> Self {
+		let mut matcher = LogMatcher::new();
+		for (key, filter) in iter {
+			matcher.insert(key, &filter);
+		}
+		matcher
+	}
+}
+
 #[cfg(test)]
 mod tests {
+	#[cfg(feature = "std")]
+	use super::LogMatcher;
 	use super::Topic;
-	#[cfg(feature = "serde")]
 	use super::TopicFilter;
 	#[cfg(not(feature = "std"))]
 	use crate::no_std_prelude::*;
-	#[cfg(feature = "serde")]
-	use crate::Hash;
+	use crate::{Hash, RawLog};
 
-	#[cfg(feature = "serde")]
 	fn hash(s: &'static str) -> Hash {
 		s.parse().unwrap()
 	}
@@ -224,4 +357,63 @@ mod tests {
 	fn test_topic_index_panic2() {
 		assert_eq!(Topic::This(10u64)[1], 10);
 	}
+
+	#[test]
+	fn test_topic_matches() {
+		assert!((Topic::Any as Topic<u64>).matches(None));
+		assert!(Topic::This(10u64).matches(Some(&10)));
+		assert!(!Topic::This(10u64).matches(Some(&20)));
+		assert!(!Topic::This(10u64).matches(None));
+		assert!(Topic::OneOf(vec![10u64, 20]).matches(Some(&20)));
+		assert!(!Topic::OneOf(vec![10u64, 20]).matches(Some(&30)));
+		assert!(Topic::OneOf(vec![]).matches(Some(&30u64)));
+	}
+
+	fn raw_log(topics: &[&'static str]) -> RawLog {
+		RawLog { topics: topics.iter().map(|topic| hash(topic)).collect(), data: vec![] }
+	}
+
+	const A: &str = "000000000000000000000000000000000000000000000000000000000000000a";
+	const B: &str = "000000000000000000000000000000000000000000000000000000000000000b";
+	const C: &str = "000000000000000000000000000000000000000000000000000000000000000c";
+
+	#[test]
+	fn test_topic_filter_matches() {
+		let filter = TopicFilter {
+			topic0: Topic::This(hash(A)),
+			topic1: Topic::Any,
+			topic2: Topic::OneOf(vec![hash(B), hash(C)]),
+			topic3: Topic::Any,
+		};
+
+		assert!(filter.matches(&raw_log(&[A, A, B])));
+		assert!(filter.matches(&raw_log(&[A, B, C, A])));
+		assert!(!filter.matches(&raw_log(&[B, A, B])));
+		assert!(!filter.matches(&raw_log(&[A, A, A])));
+		assert!(!filter.matches(&raw_log(&[A, A])));
+		assert!(TopicFilter::default().matches(&raw_log(&[])));
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn test_log_matcher() {
+		let matcher = vec![
+			("a", TopicFilter { topic0: Topic::This(hash(A)), ..Default::default() }),
+			(
+				"a_to_c",
+				TopicFilter { topic0: Topic::This(hash(A)), topic2: Topic::This(hash(C)), ..Default::default() },
+			),
+			("b_or_c", TopicFilter { topic0: Topic::OneOf(vec![hash(B), hash(C)]), ..Default::default() }),
+			("from_b", TopicFilter { topic1: Topic::This(hash(B)), ..Default::default() }),
+		]
+		.into_iter()
+		.collect::<LogMatcher<_>>();
+
+		assert_eq!(matcher.len(), 4);
+		assert_eq!(matcher.matches(&raw_log(&[A, A, C])), vec![&"a", &"a_to_c"]);
+		assert_eq!(matcher.matches(&raw_log(&[A, B, B])), vec![&"a", &"from_b"]);
+		assert_eq!(matcher.matches(&raw_log(&[C])), vec![&"b_or_c"]);
+		assert_eq!(matcher.matches(&raw_log(&[B, B])), vec![&"b_or_c", &"from_b"]);
+		assert!(matcher.matches(&raw_log(&[])).is_empty());
+	}
 }
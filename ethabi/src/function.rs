@@ -14,7 +14,8 @@ use serde::{Deserialize, Serialize};
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
 use crate::{
-	decode, encode, signature::short_signature, Bytes, Error, Param, ParamType, Result, StateMutability, Token,
+	decode, encode, signature::short_signature, Bytes, Error, NamedToken, Param, ParamType, Result, StateMutability,
+	Token,
 };
 
 /// Contract function specification.
@@ -59,7 +60,7 @@ impl Function {
 
 		let signed = short_signature(&self.name, &params).to_vec();
 		let encoded = encode(tokens);
-		Ok(signed.into_iter().chain(encoded.into_iter()).collect())
+		Ok(signed.into_iter().chain(encoded).collect())
 	}
 
 	/// Return the 4 byte short signature of this function.
@@ -78,6 +79,34 @@ impl Function {
 		decode(&self.input_param_types(), data)
 	}
 
+	/// Parses the ABI function output to tokens named after the outputs and their tuple components.
+	pub fn decode_output_named(&self, data: &[u8]) -> Result<Vec<NamedToken>> {
+		Ok(NamedToken::from_params(&self.outputs, self.decode_output(data)?))
+	}
+
+	/// Parses the ABI function input to tokens named after the inputs and their tuple components.
+	pub fn decode_input_named(&self, data: &[u8]) -> Result<Vec<NamedToken>> {
+		Ok(NamedToken::from_params(&self.inputs, self.decode_input(data)?))
+	}
+
+	/// Prepares ABI function call with input params given as JSON, see [`tokens_from_json`](crate::token::tokens_from_json).
+	#[cfg(feature = "full-serde")]
+	pub fn encode_input_json(&self, value: &serde_json::Value) -> Result<Bytes> {
+		self.encode_input(&crate::token::tokens_from_json(&self.inputs, value)?)
+	}
+
+	/// Parses the ABI function input to JSON, see [`tokens_to_json`](crate::token::tokens_to_json).
+	#[cfg(feature = "full-serde")]
+	pub fn decode_input_json(&self, data: &[u8]) -> Result<serde_json::Value> {
+		crate::token::tokens_to_json(&self.inputs, &self.decode_input(data)?)
+	}
+
+	/// Parses the ABI function output to JSON, see [`tokens_to_json`](crate::token::tokens_to_json).
+	#[cfg(feature = "full-serde")]
+	pub fn decode_output_json(&self, data: &[u8]) -> Result<serde_json::Value> {
+		crate::token::tokens_to_json(&self.outputs, &self.decode_output(data)?)
+	}
+
 	/// Returns a signature that uniquely identifies this function.
 	///
 	/// Examples:
@@ -103,7 +132,7 @@ mod tests {
 
 	#[cfg(not(feature = "std"))]
 	use crate::no_std_prelude::*;
-	use crate::{Function, Param, ParamType, StateMutability, Token};
+	use crate::{DecodedValue, Function, NamedToken, Param, ParamType, StateMutability, Token, TupleParam};
 
 	#[test]
 	fn test_function_encode_call() {
@@ -111,8 +140,8 @@ mod tests {
 		let func = Function {
 			name: "baz".to_owned(),
 			inputs: vec![
-				Param { name: "a".to_owned(), kind: ParamType::Uint(32), internal_type: None },
-				Param { name: "b".to_owned(), kind: ParamType::Bool, internal_type: None },
+				Param { name: "a".to_owned(), kind: ParamType::Uint(32), internal_type: None, components: vec![] },
+				Param { name: "b".to_owned(), kind: ParamType::Bool, internal_type: None, components: vec![] },
 			],
 			outputs: vec![],
 			constant: None,
@@ -128,4 +157,96 @@ mod tests {
 		let expected_sig = hex!("cdcd77c0").to_vec();
 		assert_eq!(func.short_signature().to_vec(), expected_sig);
 	}
+
+	#[test]
+	fn test_function_decode_output_named() {
+		#[allow(deprecated)]
+		let func = Function {
+			name: "position".to_owned(),
+			inputs: vec![],
+			outputs: vec![Param {
+				name: "".to_owned(),
+				kind: ParamType::Tuple(vec![ParamType::Uint(256), ParamType::Bool]),
+				internal_type: Some("struct Position".to_owned()),
+				components: vec![
+					TupleParam {
+						name: Some("size".to_owned()),
+						kind: ParamType::Uint(256),
+						internal_type: None,
+						components: vec![],
+					},
+					TupleParam {
+						name: Some("open".to_owned()),
+						kind: ParamType::Bool,
+						internal_type: None,
+						components: vec![],
+					},
+				],
+			}],
+			constant: None,
+			state_mutability: StateMutability::View,
+		};
+
+		let data = hex!(
+			"
+			0000000000000000000000000000000000000000000000000000000000000045
+			0000000000000000000000000000000000000000000000000000000000000001
+			"
+		);
+		let expected = vec![NamedToken {
+			name: "".to_owned(),
+			internal_type: Some("struct Position".to_owned()),
+			value: DecodedValue::Tuple(vec![
+				NamedToken {
+					name: "size".to_owned(),
+					internal_type: None,
+					value: DecodedValue::Token(Token::Uint(69.into())),
+				},
+				NamedToken {
+					name: "open".to_owned(),
+					internal_type: None,
+					value: DecodedValue::Token(Token::Bool(true)),
+				},
+			]),
+		}];
+		assert_eq!(func.decode_output_named(&data).unwrap(), expected);
+		assert!(func.decode_input_named(&[]).unwrap().is_empty());
+	}
+
+	#[cfg(feature = "full-serde")]
+	#[test]
+	fn test_function_json() {
+		#[allow(deprecated)]
+		let func = Function {
+			name: "transfer".to_owned(),
+			inputs: vec![
+				Param { name: "to".to_owned(), kind: ParamType::Address, internal_type: None, components: vec![] },
+				Param {
+					name: "amount".to_owned(),
+					kind: ParamType::Uint(256),
+					internal_type: None,
+					components: vec![],
+				},
+			],
+			outputs: vec![Param {
+				name: "".to_owned(),
+				kind: ParamType::Bool,
+				internal_type: None,
+				components: vec![],
+			}],
+			constant: None,
+			state_mutability: StateMutability::NonPayable,
+		};
+
+		let input = serde_json::json!({ "to": "0x1111111111111111111111111111111111111111", "amount": "1000" });
+		let encoded = func.encode_input_json(&input).unwrap();
+		assert_eq!(
+			encoded,
+			func.encode_input(&[Token::Address([0x11u8; 20].into()), Token::Uint(1000.into())]).unwrap()
+		);
+		assert_eq!(func.decode_input_json(&encoded[4..]).unwrap(), input);
+
+		let output = hex!("0000000000000000000000000000000000000000000000000000000000000001");
+		assert_eq!(func.decode_output_json(&output).unwrap(), serde_json::json!([true]));
+	}
 }
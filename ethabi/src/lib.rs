@@ -30,6 +30,11 @@ mod no_std_prelude {
 #[cfg(not(feature = "std"))]
 use no_std_prelude::*;
 
+#[cfg(feature = "full-serde")]
+mod abi_document;
+#[cfg(feature = "full-serde")]
+pub mod artifact;
+mod bytecode;
 mod constructor;
 mod contract;
 mod decoder;
@@ -40,15 +45,23 @@ mod event;
 mod event_param;
 mod filter;
 mod function;
+#[cfg(feature = "full-serde")]
+mod loader;
 mod log;
+#[cfg(feature = "std")]
+mod log_decoder;
+mod log_filter;
+pub mod multicall;
+mod named_token;
 #[cfg(feature = "serde")]
 mod operation;
 mod param;
 pub mod param_type;
+mod revert;
 mod signature;
 mod state_mutability;
+pub mod storage;
 pub mod token;
-#[cfg(feature = "serde")]
 mod tuple_param;
 mod util;
 
@@ -57,25 +70,40 @@ mod tests;
 
 pub use ethereum_types;
 
-#[cfg(feature = "serde")]
-pub use crate::tuple_param::TupleParam;
+#[cfg(feature = "full-serde")]
+pub use crate::{
+	abi_document::{AbiDocument, AbiItem, AbiParam},
+	artifact::Artifact,
+	loader::{LoadReport, LoadWarning},
+	log::LogObject,
+};
 pub use crate::{
+	bytecode::{Bytecode, LinkOffset, LinkReferences, Placeholder, PlaceholderKind},
 	constructor::Constructor,
 	contract::{Contract, Events, Functions},
 	decoder::{decode, decode_validate},
-	encoder::encode,
+	encoder::{encode, encode_packed},
 	error::Error as AbiError,
 	errors::{Error, Result},
-	event::Event,
+	event::{topic_for, Event},
 	event_param::EventParam,
 	filter::{RawTopicFilter, Topic, TopicFilter},
 	function::Function,
 	log::{Log, LogFilter, LogParam, ParseLog, RawLog},
+	log_filter::{BlockNumber, LogEntry, LogFilterRequest},
+	named_token::{DecodedValue, NamedToken},
 	param::Param,
 	param_type::ParamType,
+	revert::Revert,
 	signature::{long_signature, short_signature},
 	state_mutability::StateMutability,
 	token::Token,
+	tuple_param::TupleParam,
+};
+#[cfg(feature = "std")]
+pub use crate::{
+	filter::LogMatcher,
+	log_decoder::{DecodeStats, DecodedBatch, DecodedLog, LogDecoder},
 };
 
 /// ABI word.
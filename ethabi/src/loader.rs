@@ -0,0 +1,269 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Lenient and strict ABI loading.
+
+use std::{borrow::Cow, collections::HashSet, fmt};
+
+use crate::{errors, operation::Operation, AbiDocument, AbiItem, Contract, Error, StateMutability};
+
+/// Issue found in an ABI item while loading leniently.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadWarning {
+	/// Position of the item in the ABI.
+	pub item: usize,
+	/// Whether the item was left out of the contract.
+	pub skipped: bool,
+	/// Description of the issue.
+	pub message: String,
+}
+
+impl fmt::Display for LoadWarning {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.skipped {
+			true => write!(f, "item {}: {}, skipped", self.item, self.message),
+			false => write!(f, "item {}: {}", self.item, self.message),
+		}
+	}
+}
+
+/// Warnings collected while loading an ABI leniently.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LoadReport {
+	/// Issues found, in document order.
+	pub warnings: Vec<LoadWarning>,
+}
+
+impl LoadReport {
+	/// Returns true if the ABI loaded without any issue.
+	pub fn is_clean(&self) -> bool {
+		self.warnings.is_empty()
+	}
+
+	/// Returns the positions of the items left out of the contract.
+	pub fn skipped(&self) -> impl Iterator<Item = usize> + '_ {
+		self.warnings.iter().filter(|warning| warning.skipped).map(|warning| warning.item)
+	}
+}
+
+/// Loads `document` into a contract.
+///
+/// Defaults of the Solidity ABI spec are applied in both modes: an omitted `type` is a function, omitted
+/// `inputs` and `outputs` are empty and a missing `stateMutability` is derived from the legacy `constant`
+/// and `payable` fields. The lenient mode reports anything questionable and skips items it cannot
+/// load, the strict mode fails on the first of them.
+pub(crate) fn load(document: AbiDocument, strict: bool) -> errors::Result<(Contract, LoadReport)> {
+	let mut loader = Loader { strict, contract: Contract::default(), report: LoadReport::default() };
+	let mut signatures = HashSet::new();
+
+	for (index, item) in document.items.into_iter().enumerate() {
+		let item = match loader.normalize(index, item)? {
+			Some(item) => item,
+			None => continue,
+		};
+
+		let operation = match serde_json::to_value(&item).and_then(serde_json::from_value::<Operation>) {
+			Ok(operation) => operation,
+			Err(err) => {
+				loader.warn(index, true, format!("invalid {}: {}", item.item_type(), err))?;
+				continue;
+			}
+		};
+
+		let (signature, description) = match operation {
+			Operation::Constructor(_) => (None, "constructor".to_owned()),
+			Operation::Function(ref function) => (Some(function.short_signature().to_vec()), function.signature()),
+			Operation::Event(ref event) => (Some(event.signature().as_bytes().to_vec()), event.name.clone()),
+			Operation::Error(ref error) => (Some(error.signature().as_bytes().to_vec()), error.name.clone()),
+			Operation::Fallback => (None, "fallback".to_owned()),
+			Operation::Receive => (None, "receive".to_owned()),
+		};
+		if !signatures.insert((item.item_type().to_owned(), signature)) {
+			loader.warn(index, true, format!("duplicate {} {description}", item.item_type()))?;
+			continue;
+		}
+
+		loader.contract.insert(operation);
+	}
+
+	Ok((loader.contract, loader.report))
+}
+
+struct Loader {
+	strict: bool,
+	contract: Contract,
+	report: LoadReport,
+}
+
+impl Loader {
+	fn warn(&mut self, item: usize, skipped: bool, message: String) -> errors::Result<()> {
+		let warning = LoadWarning { item, skipped, message };
+		if self.strict {
+			return Err(Error::Other(Cow::Owned(warning.to_string())));
+		}
+		self.report.warnings.push(warning);
+		Ok(())
+	}
+
+	/// Applies the defaults to `item`, returning `None` if it should be skipped.
+	fn normalize(&mut self, index: usize, mut item: AbiItem) -> errors::Result<Option<AbiItem>> {
+		if item.item_type.is_none() {
+			self.warn(index, false, "missing type, assuming function".to_owned())?;
+			item.item_type = Some("function".to_owned());
+		}
+
+		match item.item_type() {
+			"function" => {
+				item.inputs.get_or_insert_with(Vec::new);
+				if item.outputs.is_none() {
+					self.warn(index, false, "missing outputs, assuming none".to_owned())?;
+					item.outputs = Some(Vec::new());
+				}
+				self.state_mutability(index, &mut item)?;
+			}
+			"constructor" => {
+				item.inputs.get_or_insert_with(Vec::new);
+				self.state_mutability(index, &mut item)?;
+			}
+			"event" => {
+				item.inputs.get_or_insert_with(Vec::new);
+				item.anonymous.get_or_insert(false);
+			}
+			"error" => {
+				item.inputs.get_or_insert_with(Vec::new);
+			}
+			"fallback" | "receive" => {}
+			other => {
+				let message = format!("unknown type `{other}`");
+				self.warn(index, true, message)?;
+				return Ok(None);
+			}
+		}
+
+		Ok(Some(item))
+	}
+
+	/// Derives the state mutability of functions and constructors written before Solidity 0.4.16.
+	fn state_mutability(&mut self, index: usize, item: &mut AbiItem) -> errors::Result<()> {
+		let legacy = match (item.constant, item.payable) {
+			(_, Some(true)) => Some(StateMutability::Payable),
+			(Some(true), _) => Some(StateMutability::View),
+			(Some(false), _) | (_, Some(false)) => Some(StateMutability::NonPayable),
+			(None, None) => None,
+		};
+
+		match (item.state_mutability, legacy) {
+			(None, Some(legacy)) => {
+				// `constant` does not tell `view` and `pure` apart
+				self.warn(index, false, "legacy `constant`/`payable` without `stateMutability`".to_owned())?;
+				item.state_mutability = Some(legacy);
+			}
+			(Some(state_mutability), Some(_)) => {
+				let constant = matches!(state_mutability, StateMutability::View | StateMutability::Pure);
+				let payable = state_mutability == StateMutability::Payable;
+				if item.constant.is_some_and(|c| c != constant) || item.payable.is_some_and(|p| p != payable) {
+					let message = "legacy `constant`/`payable` conflict with `stateMutability`, using the latter";
+					self.warn(index, false, message.to_owned())?;
+				}
+			}
+			(_, None) => {}
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{Contract, LoadWarning, StateMutability};
+
+	#[test]
+	fn res_corpus() {
+		let strict = [
+			include_str!("../../res/constructor.abi"),
+			include_str!("../../res/event.abi"),
+			include_str!("../../res/Multicall3.abi"),
+			include_str!("../../res/test.abi"),
+			include_str!("../../res/test_rust_keywords.abi"),
+			include_str!("../../res/Validators.abi"),
+		];
+		let legacy = [
+			include_str!("../../res/eip20.abi"),
+			include_str!("../../res/foo.abi"),
+			include_str!("../../res/Operations.abi"),
+			include_str!("../../res/urlhint.abi"),
+		];
+
+		for abi in strict {
+			let (contract, report) = Contract::load_lenient(abi.as_bytes()).unwrap();
+			assert!(report.is_clean(), "{report:?}");
+			assert_eq!(contract, Contract::load(abi.as_bytes()).unwrap());
+			assert_eq!(Contract::load_strict(abi.as_bytes()).unwrap(), contract);
+		}
+		for abi in legacy {
+			let (contract, report) = Contract::load_lenient(abi.as_bytes()).unwrap();
+			// legacy items are reported, none is skipped
+			assert!(!report.is_clean() && report.skipped().next().is_none(), "{report:?}");
+			let signatures = |contract: &Contract| contract.functions().map(|f| f.signature()).collect::<Vec<_>>();
+			assert_eq!(signatures(&contract), signatures(&Contract::load(abi.as_bytes()).unwrap()));
+			assert!(Contract::load_strict(abi.as_bytes()).is_err());
+		}
+	}
+
+	#[test]
+	fn legacy_defaults() {
+		let abi = r#"[
+			{ "name": "a", "inputs": [], "outputs": [], "constant": true },
+			{ "type": "function", "name": "b", "inputs": [], "payable": true },
+			{ "type": "function", "name": "c", "constant": false }
+		]"#;
+
+		let legacy = "legacy `constant`/`payable` without `stateMutability`";
+		let (contract, report) = Contract::load_lenient(abi.as_bytes()).unwrap();
+		assert_eq!(contract.function("a").unwrap().state_mutability, StateMutability::View);
+		assert_eq!(contract.function("b").unwrap().state_mutability, StateMutability::Payable);
+		assert_eq!(contract.function("c").unwrap().state_mutability, StateMutability::NonPayable);
+		assert_eq!(
+			report.warnings,
+			vec![
+				LoadWarning { item: 0, skipped: false, message: "missing type, assuming function".to_owned() },
+				LoadWarning { item: 0, skipped: false, message: legacy.to_owned() },
+				LoadWarning { item: 1, skipped: false, message: "missing outputs, assuming none".to_owned() },
+				LoadWarning { item: 1, skipped: false, message: legacy.to_owned() },
+				LoadWarning { item: 2, skipped: false, message: "missing outputs, assuming none".to_owned() },
+				LoadWarning { item: 2, skipped: false, message: legacy.to_owned() },
+			]
+		);
+
+		let err = Contract::load_strict(abi.as_bytes()).unwrap_err();
+		assert_eq!(err.to_string(), "item 0: missing type, assuming function");
+	}
+
+	#[test]
+	fn skips_unknown_and_invalid_items() {
+		let abi = r#"[
+			{ "type": "function", "name": "a", "inputs": [], "outputs": [] },
+			{ "type": "modifier", "name": "onlyOwner", "inputs": [] },
+			{ "type": "function", "name": "b", "inputs": [{ "name": "x", "type": "uint256x" }], "outputs": [] },
+			{ "type": "function", "name": "a", "inputs": [], "outputs": [], "stateMutability": "view" },
+			{ "type": "function", "name": "c", "inputs": [], "outputs": [], "constant": true, "stateMutability": "payable" }
+		]"#;
+
+		let (contract, report) = Contract::load_lenient(abi.as_bytes()).unwrap();
+		assert_eq!(contract.functions().map(|f| f.name.as_str()).collect::<Vec<_>>(), vec!["a", "c"]);
+		assert_eq!(contract.function("a").unwrap().state_mutability, StateMutability::NonPayable);
+		assert_eq!(contract.function("c").unwrap().state_mutability, StateMutability::Payable);
+		assert_eq!(report.skipped().collect::<Vec<_>>(), vec![1, 2, 3]);
+		assert_eq!(report.warnings[0].to_string(), "item 1: unknown type `modifier`, skipped");
+		assert_eq!(report.warnings[2].to_string(), "item 3: duplicate function a(), skipped");
+		assert_eq!(report.warnings[3].item, 4);
+		assert!(!report.warnings[3].skipped);
+
+		assert!(Contract::load_strict(abi.as_bytes()).is_err());
+	}
+}
@@ -6,11 +6,21 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use alloc::collections::BTreeMap;
+use core::ops;
+
+#[cfg(feature = "full-serde")]
+use serde::{
+	ser::{Error as _, SerializeMap},
+	Serializer,
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
+#[cfg(feature = "full-serde")]
+use crate::{token::event_param_to_json, Event};
 use crate::{Bytes, Hash, Result, Token, TopicFilter};
 
 /// Common filtering functions that are available for any event.
@@ -62,3 +72,185 @@ pub struct Log {
 	/// Log params.
 	pub params: Vec<LogParam>,
 }
+
+impl Log {
+	/// Returns the value of the param with the given name.
+	pub fn get(&self, name: &str) -> Option<&Token> {
+		self.params.iter().find(|param| param.name == name).map(|param| &param.value)
+	}
+
+	/// Converts the log into a map of param values by param name.
+	pub fn into_map(self) -> BTreeMap<String, Token> {
+		self.params.into_iter().map(|param| (param.name, param.value)).collect()
+	}
+
+	/// Returns a view of the log serializing to an object with the param values by param name.
+	///
+	/// Values are converted according to the types of the inputs of `event`, the event the log was
+	/// parsed with.
+	#[cfg(feature = "full-serde")]
+	pub fn as_object<'a>(&'a self, event: &'a Event) -> LogObject<'a> {
+		LogObject { log: self, event }
+	}
+}
+
+impl ops::Index<usize> for Log {
+	type Output = Token;
+
+	fn index(&self, index: usize) -> &Self::Output {
+		&self.params[index].value
+	}
+}
+
+/// Log serializing to an object with the param values by param name, see [`Log::as_object`].
+///
+/// Values are converted as by [`token_to_json`](crate::token::token_to_json), with tuple fields
+/// named after the event param components. Unnamed params, and params sharing their name with
+/// another, are keyed by position as `param{index}`. Serialization fails if the log params do not
+/// match the event inputs.
+#[cfg(feature = "full-serde")]
+#[derive(Debug, Clone, Copy)]
+pub struct LogObject<'a> {
+	log: &'a Log,
+	event: &'a Event,
+}
+
+#[cfg(feature = "full-serde")]
+impl Serialize for LogObject<'_> {
+	fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let params = &self.log.params;
+		let inputs = &self.event.inputs;
+		if params.len() != inputs.len() {
+			return Err(S::Error::custom(format!(
+				"log has {} params but event {} has {} inputs",
+				params.len(),
+				self.event.name,
+				inputs.len()
+			)));
+		}
+		let mut map = serializer.serialize_map(Some(params.len()))?;
+		for (index, (param, input)) in params.iter().zip(inputs).enumerate() {
+			let unique = !param.name.is_empty() && params.iter().filter(|p| p.name == param.name).count() == 1;
+			let key = if unique { param.name.clone() } else { format!("param{index}") };
+			let value = event_param_to_json(input, &param.value).map_err(S::Error::custom)?;
+			map.serialize_entry(&key, &value)?;
+		}
+		map.end()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[cfg(not(feature = "std"))]
+	use crate::no_std_prelude::*;
+	use crate::{Log, LogParam, Token};
+
+	fn log() -> Log {
+		Log {
+			params: vec![
+				LogParam { name: "from".to_owned(), value: Token::Address([0x11u8; 20].into()) },
+				LogParam { name: "value".to_owned(), value: Token::Uint(7.into()) },
+			],
+		}
+	}
+
+	#[test]
+	fn get() {
+		let log = log();
+		assert_eq!(log.get("value"), Some(&Token::Uint(7.into())));
+		assert_eq!(log.get("to"), None);
+		assert_eq!(log[0], Token::Address([0x11u8; 20].into()));
+	}
+
+	#[test]
+	fn into_map() {
+		let map = log().into_map();
+		assert_eq!(map.len(), 2);
+		assert_eq!(map["value"], Token::Uint(7.into()));
+	}
+
+	#[cfg(feature = "full-serde")]
+	fn event(inputs: Vec<crate::EventParam>) -> crate::Event {
+		crate::Event { name: "Sample".to_owned(), inputs, anonymous: false }
+	}
+
+	#[cfg(feature = "full-serde")]
+	#[test]
+	fn serialize_object() {
+		use crate::{EventParam, ParamType};
+
+		let transfer = event(vec![
+			EventParam::new("from", ParamType::Address, true),
+			EventParam::new("value", ParamType::Uint(256), false),
+		]);
+		let expected = r#"{"from":"0x1111111111111111111111111111111111111111","value":"7"}"#;
+		assert_eq!(serde_json::to_string(&log().as_object(&transfer)).unwrap(), expected);
+
+		let sample = event(vec![
+			EventParam::new("", ParamType::Bool, false),
+			EventParam::new("id", ParamType::FixedBytes(2), false),
+			EventParam::new("id", ParamType::Array(Box::new(ParamType::Int(256))), false),
+			EventParam::new("topic", ParamType::String, true),
+		]);
+		let log = Log {
+			params: vec![
+				LogParam { name: "".to_owned(), value: Token::Bool(true) },
+				LogParam { name: "id".to_owned(), value: Token::FixedBytes(vec![0x12, 0x34]) },
+				LogParam { name: "id".to_owned(), value: Token::Array(vec![Token::Int(!crate::Int::zero())]) },
+				LogParam { name: "topic".to_owned(), value: Token::HashedTopic([0x22u8; 32].into()) },
+			],
+		};
+		let expected = serde_json::json!({
+			"param0": true,
+			"param1": "0x1234",
+			"param2": ["-1"],
+			"topic": "0x2222222222222222222222222222222222222222222222222222222222222222",
+		});
+		assert_eq!(serde_json::to_value(log.as_object(&sample)).unwrap(), expected);
+	}
+
+	#[cfg(feature = "full-serde")]
+	#[test]
+	fn serialize_object_with_event_types() {
+		use crate::{EventParam, ParamType, TupleParam};
+
+		let nested = ParamType::Array(Box::new(ParamType::Array(Box::new(ParamType::Uint(256)))));
+		let order = ParamType::Tuple(vec![ParamType::Uint(8), ParamType::Bool]);
+		let sample = event(vec![
+			EventParam::new("amounts", nested, false),
+			EventParam::new("order", order, false).with_components(vec![
+				TupleParam::new("size", ParamType::Uint(8)),
+				TupleParam::new("open", ParamType::Bool),
+			]),
+		]);
+		let log = Log {
+			params: vec![
+				LogParam {
+					name: "amounts".to_owned(),
+					value: Token::Array(vec![Token::Array(vec![]), Token::Array(vec![Token::Uint(1.into())])]),
+				},
+				LogParam {
+					name: "order".to_owned(),
+					value: Token::Tuple(vec![Token::Uint(3.into()), Token::Bool(true)]),
+				},
+			],
+		};
+		let expected = serde_json::json!({
+			"amounts": [[], ["1"]],
+			"order": { "size": "3", "open": true },
+		});
+		assert_eq!(serde_json::to_value(log.as_object(&sample)).unwrap(), expected);
+
+		// the log was not parsed with this event
+		let transfer = event(vec![EventParam::new("from", ParamType::Address, true)]);
+		assert!(serde_json::to_value(log.as_object(&transfer)).is_err());
+		let mismatch = event(vec![
+			EventParam::new("amounts", ParamType::Array(Box::new(ParamType::Bool)), false),
+			EventParam::new("order", ParamType::Bool, false),
+		]);
+		assert!(serde_json::to_value(log.as_object(&mismatch)).is_err());
+	}
+}
@@ -0,0 +1,297 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Log decoding across many contracts.
+
+use std::collections::HashMap;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{Address, Contract, Event, Hash, Log, LogEntry, Result};
+
+/// Event registered for a contract address.
+#[derive(Debug, Clone)]
+struct Registered {
+	contract: String,
+	event: Event,
+}
+
+/// Log decoded by a [`LogDecoder`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedLog<'a> {
+	/// Contract which emitted the log.
+	pub address: Address,
+	/// Name the contract was registered with.
+	pub contract: &'a str,
+	/// Decoded event.
+	pub event: &'a Event,
+	/// Decoded params.
+	pub log: Log,
+}
+
+/// Statistics of a decoded batch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DecodeStats {
+	/// Number of decoded logs.
+	pub decoded: usize,
+	/// Number of logs without any registered event, by their first topic.
+	pub unknown_topics: HashMap<Hash, usize>,
+	/// Number of logs without topics, e.g. emitted by anonymous events.
+	pub no_topics: usize,
+	/// Number of logs matching a registered event which could not be decoded.
+	pub failed: usize,
+}
+
+/// Result of decoding a batch of logs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedBatch<'a> {
+	/// Decoded logs, in the order of the batch, `None` for logs which could not be decoded.
+	pub logs: Vec<Option<DecodedLog<'a>>>,
+	/// Batch statistics.
+	pub stats: DecodeStats,
+}
+
+/// Outcome of decoding a single log.
+enum Outcome<'a> {
+	Decoded(DecodedLog<'a>),
+	Unknown(Hash),
+	NoTopics,
+	Failed,
+}
+
+/// Registry of events of many contracts, keyed by contract address and event signature.
+///
+/// Anonymous events have no signature topic and are not registered.
+#[derive(Debug, Clone, Default)]
+pub struct LogDecoder {
+	/// Events by emitting address, `None` for events registered for any address.
+	events: HashMap<(Option<Address>, Hash), Vec<Registered>>,
+}
+
+impl LogDecoder {
+	/// Creates an empty registry.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Registers the events of `contract` deployed at `address` under the given name.
+	pub fn register(&mut self, address: Address, name: &str, contract: &Contract) {
+		self.insert(Some(address), name, contract);
+	}
+
+	/// Registers the events of `contract` under the given name, for logs emitted by any address.
+	///
+	/// Events registered for the address of a log are tried first.
+	pub fn register_any(&mut self, name: &str, contract: &Contract) {
+		self.insert(None, name, contract);
+	}
+
+	fn insert(&mut self, address: Option<Address>, name: &str, contract: &Contract) {
+		for event in contract.events().filter(|event| !event.anonymous) {
+			let registered = Registered { contract: name.to_owned(), event: event.clone() };
+			self.events.entry((address, event.signature())).or_default().push(registered);
+		}
+	}
+
+	/// Returns the number of registered events.
+	pub fn len(&self) -> usize {
+		self.events.values().map(Vec::len).sum()
+	}
+
+	/// Returns true if there are no registered events.
+	pub fn is_empty(&self) -> bool {
+		self.events.is_empty()
+	}
+
+	/// Decodes a single log, returning `None` if no event is registered for it.
+	///
+	/// Events sharing a signature, like the ERC-20 and ERC-721 `Transfer`, are tried in the order
+	/// they were registered.
+	pub fn decode(&self, entry: &LogEntry) -> Result<Option<DecodedLog<'_>>> {
+		match self.decode_outcome(entry) {
+			Outcome::Decoded(log) => Ok(Some(log)),
+			Outcome::Unknown(_) | Outcome::NoTopics => Ok(None),
+			Outcome::Failed => Err(crate::Error::InvalidData),
+		}
+	}
+
+	/// Decodes a batch of logs, in parallel with the `rayon` feature.
+	pub fn decode_batch(&self, entries: &[LogEntry]) -> DecodedBatch<'_> {
+		#[cfg(feature = "rayon")]
+		let outcomes: Vec<_> = entries.par_iter().map(|entry| self.decode_outcome(entry)).collect();
+		#[cfg(not(feature = "rayon"))]
+		let outcomes: Vec<_> = entries.iter().map(|entry| self.decode_outcome(entry)).collect();
+
+		let mut stats = DecodeStats::default();
+		let logs = outcomes
+			.into_iter()
+			.map(|outcome| match outcome {
+				Outcome::Decoded(log) => {
+					stats.decoded += 1;
+					Some(log)
+				}
+				Outcome::Unknown(topic) => {
+					*stats.unknown_topics.entry(topic).or_default() += 1;
+					None
+				}
+				Outcome::NoTopics => {
+					stats.no_topics += 1;
+					None
+				}
+				Outcome::Failed => {
+					stats.failed += 1;
+					None
+				}
+			})
+			.collect();
+
+		DecodedBatch { logs, stats }
+	}
+
+	fn decode_outcome(&self, entry: &LogEntry) -> Outcome<'_> {
+		let topic0 = match entry.log.topics.first() {
+			Some(topic0) => *topic0,
+			None => return Outcome::NoTopics,
+		};
+		let for_address = self.events.get(&(Some(entry.address), topic0));
+		let for_any = self.events.get(&(None, topic0));
+		if for_address.is_none() && for_any.is_none() {
+			return Outcome::Unknown(topic0);
+		}
+
+		for_address
+			.into_iter()
+			.chain(for_any)
+			.flatten()
+			.find_map(|registered| {
+				let log = registered.event.parse_log(entry.log.clone()).ok()?;
+				Some(DecodedLog {
+					address: entry.address,
+					contract: &registered.contract,
+					event: &registered.event,
+					log,
+				})
+			})
+			.map_or(Outcome::Failed, Outcome::Decoded)
+	}
+}
+
+#[cfg(all(test, feature = "full-serde"))]
+mod tests {
+	use hex_literal::hex;
+
+	use super::LogDecoder;
+	use crate::{Address, Contract, Hash, LogEntry, LogParam, RawLog, Token};
+
+	const ERC20: &str = r#"[{
+		"type": "event",
+		"name": "Transfer",
+		"inputs": [
+			{ "name": "from", "type": "address", "indexed": true },
+			{ "name": "to", "type": "address", "indexed": true },
+			{ "name": "value", "type": "uint256", "indexed": false }
+		],
+		"anonymous": false
+	}]"#;
+
+	const ERC721: &str = r#"[{
+		"type": "event",
+		"name": "Transfer",
+		"inputs": [
+			{ "name": "from", "type": "address", "indexed": true },
+			{ "name": "to", "type": "address", "indexed": true },
+			{ "name": "tokenId", "type": "uint256", "indexed": true }
+		],
+		"anonymous": false
+	}]"#;
+
+	fn transfer(address: Address, value_indexed: bool) -> LogEntry {
+		let signature: Hash = hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef").into();
+		let from: Hash = hex!("0000000000000000000000001111111111111111111111111111111111111111").into();
+		let to: Hash = hex!("0000000000000000000000002222222222222222222222222222222222222222").into();
+		let value = hex!("0000000000000000000000000000000000000000000000000000000000000007");
+
+		let log = match value_indexed {
+			true => RawLog { topics: vec![signature, from, to, value.into()], data: vec![] },
+			false => RawLog { topics: vec![signature, from, to], data: value.to_vec() },
+		};
+		(address, log).into()
+	}
+
+	fn decoder() -> LogDecoder {
+		let mut decoder = LogDecoder::new();
+		decoder.register([0xaa; 20].into(), "token", &Contract::load(ERC20.as_bytes()).unwrap());
+		decoder.register([0xbb; 20].into(), "nft", &Contract::load(ERC721.as_bytes()).unwrap());
+		// same address may host both, e.g. behind a proxy
+		decoder.register([0xcc; 20].into(), "erc20", &Contract::load(ERC20.as_bytes()).unwrap());
+		decoder.register([0xcc; 20].into(), "erc721", &Contract::load(ERC721.as_bytes()).unwrap());
+		decoder
+	}
+
+	#[test]
+	fn decode_single() {
+		let decoder = decoder();
+		assert_eq!(decoder.len(), 4);
+
+		let decoded = decoder.decode(&transfer([0xaa; 20].into(), false)).unwrap().unwrap();
+		assert_eq!(decoded.contract, "token");
+		assert_eq!(decoded.event.name, "Transfer");
+		assert_eq!(decoded.log.params[2], LogParam { name: "value".to_owned(), value: Token::Uint(7.into()) });
+
+		assert_eq!(decoder.decode(&transfer([0xdd; 20].into(), false)).unwrap(), None);
+		assert!(decoder.decode(&transfer([0xaa; 20].into(), true)).is_err());
+	}
+
+	#[test]
+	fn decode_shared_signature() {
+		let decoder = decoder();
+		let erc20 = decoder.decode(&transfer([0xcc; 20].into(), false)).unwrap().unwrap();
+		assert_eq!(erc20.contract, "erc20");
+		let erc721 = decoder.decode(&transfer([0xcc; 20].into(), true)).unwrap().unwrap();
+		assert_eq!(erc721.contract, "erc721");
+		assert_eq!(erc721.log.params[2].name, "tokenId");
+	}
+
+	#[test]
+	fn decode_any_address() {
+		let mut decoder = decoder();
+		decoder.register_any("any", &Contract::load(ERC721.as_bytes()).unwrap());
+		assert_eq!(decoder.len(), 5);
+
+		let token = decoder.decode(&transfer([0xaa; 20].into(), false)).unwrap().unwrap();
+		assert_eq!(token.contract, "token");
+		let any = decoder.decode(&transfer([0xaa; 20].into(), true)).unwrap().unwrap();
+		assert_eq!(any.contract, "any");
+		let any = decoder.decode(&transfer([0xdd; 20].into(), true)).unwrap().unwrap();
+		assert_eq!(any.contract, "any");
+		assert!(decoder.decode(&transfer([0xdd; 20].into(), false)).is_err());
+	}
+
+	#[test]
+	fn decode_batch() {
+		let decoder = decoder();
+		let unknown: Hash = [0x99; 32].into();
+		let entries = vec![
+			transfer([0xaa; 20].into(), false),
+			transfer([0xbb; 20].into(), true),
+			(Address::from([0xaa; 20]), RawLog { topics: vec![unknown], data: vec![] }).into(),
+			(Address::from([0xaa; 20]), RawLog { topics: vec![unknown], data: vec![] }).into(),
+			(Address::from([0xaa; 20]), RawLog { topics: vec![], data: vec![] }).into(),
+			transfer([0xbb; 20].into(), false),
+		];
+
+		let batch = decoder.decode_batch(&entries);
+		let contracts: Vec<_> = batch.logs.iter().map(|log| log.as_ref().map(|log| log.contract)).collect();
+		assert_eq!(contracts, vec![Some("token"), Some("nft"), None, None, None, None]);
+		assert_eq!(batch.stats.decoded, 2);
+		assert_eq!(batch.stats.unknown_topics.get(&unknown), Some(&2));
+		assert_eq!(batch.stats.no_topics, 1);
+		assert_eq!(batch.stats.failed, 1);
+	}
+}
@@ -0,0 +1,236 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `eth_getLogs` filter object.
+
+#[cfg(feature = "serde")]
+use serde::{ser::SerializeMap, Serialize, Serializer};
+
+use crate::{Address, Hash, RawLog, Topic, TopicFilter};
+
+/// Block of a log filter range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlockNumber {
+	/// Most recent block.
+	#[default]
+	Latest,
+	/// Genesis block.
+	Earliest,
+	/// Pending block.
+	Pending,
+	/// Most recent safe block.
+	Safe,
+	/// Most recent finalized block.
+	Finalized,
+	/// Block with the given number.
+	Number(u64),
+}
+
+impl From<u64> for BlockNumber {
+	fn from(number: u64) -> Self {
+		BlockNumber::Number(number)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for BlockNumber {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		match *self {
+			BlockNumber::Latest => serializer.serialize_str("latest"),
+			BlockNumber::Earliest => serializer.serialize_str("earliest"),
+			BlockNumber::Pending => serializer.serialize_str("pending"),
+			BlockNumber::Safe => serializer.serialize_str("safe"),
+			BlockNumber::Finalized => serializer.serialize_str("finalized"),
+			BlockNumber::Number(number) => serializer.serialize_str(&format!("{number:#x}")),
+		}
+	}
+}
+
+/// Filter object of an `eth_getLogs` request.
+///
+/// `block_hash` restricts the logs to a single block and cannot be combined with a block range.
+#[derive(Debug, PartialEq, Eq, Default)]
+pub struct LogFilterRequest {
+	/// Contracts emitting the logs.
+	pub address: Topic<Address>,
+	/// First block of the range.
+	pub from_block: Option<BlockNumber>,
+	/// Last block of the range.
+	pub to_block: Option<BlockNumber>,
+	/// Hash of the block containing the logs.
+	pub block_hash: Option<Hash>,
+	/// Topics of the logs.
+	pub topics: TopicFilter,
+}
+
+/// Log together with the contract and block it comes from.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LogEntry {
+	/// Contract which emitted the log.
+	pub address: Address,
+	/// Number of the block containing the log, if known.
+	pub block_number: Option<u64>,
+	/// Hash of the block containing the log, if known.
+	pub block_hash: Option<Hash>,
+	/// Log topics and data.
+	pub log: RawLog,
+}
+
+impl From<(Address, RawLog)> for LogEntry {
+	fn from((address, log): (Address, RawLog)) -> Self {
+		LogEntry { address, block_number: None, block_hash: None, log }
+	}
+}
+
+impl LogFilterRequest {
+	/// Returns true if `entry` satisfies the filter.
+	///
+	/// Block tags other than `earliest` depend on the chain head and do not restrict the range
+	/// locally. Entries without a known block never match a filter restricting the block.
+	pub fn matches(&self, entry: &LogEntry) -> bool {
+		let after_from = match self.from_block {
+			Some(BlockNumber::Number(from)) => entry.block_number.is_some_and(|number| number >= from),
+			_ => true,
+		};
+		let before_to = match self.to_block {
+			Some(BlockNumber::Number(to)) => entry.block_number.is_some_and(|number| number <= to),
+			Some(BlockNumber::Earliest) => entry.block_number == Some(0),
+			_ => true,
+		};
+		let in_block = match self.block_hash {
+			Some(hash) => entry.block_hash == Some(hash),
+			None => true,
+		};
+
+		self.address.matches(Some(&entry.address))
+			&& after_from
+			&& before_to
+			&& in_block
+			&& self.topics.matches(&entry.log)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for LogFilterRequest {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		use serde::ser::Error;
+
+		if self.block_hash.is_some() && (self.from_block.is_some() || self.to_block.is_some()) {
+			return Err(S::Error::custom("blockHash cannot be combined with fromBlock or toBlock"));
+		}
+
+		let mut map = serializer.serialize_map(None)?;
+		if !self.address.is_any() {
+			map.serialize_entry("address", &self.address)?;
+		}
+		if let Some(ref from_block) = self.from_block {
+			map.serialize_entry("fromBlock", from_block)?;
+		}
+		if let Some(ref to_block) = self.to_block {
+			map.serialize_entry("toBlock", to_block)?;
+		}
+		if let Some(ref block_hash) = self.block_hash {
+			map.serialize_entry("blockHash", block_hash)?;
+		}
+		map.serialize_entry("topics", &self.topics)?;
+		map.end()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{BlockNumber, LogEntry, LogFilterRequest};
+	use crate::{Address, Hash, RawLog, Topic, TopicFilter};
+
+	fn hash(byte: u8) -> Hash {
+		[byte; 32].into()
+	}
+
+	fn address(byte: u8) -> Address {
+		[byte; 20].into()
+	}
+
+	fn entry(contract: u8, block_number: u64) -> LogEntry {
+		LogEntry {
+			address: address(contract),
+			block_number: Some(block_number),
+			block_hash: Some(hash(0xbb)),
+			log: RawLog { topics: vec![hash(0xaa)], data: vec![] },
+		}
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serialize_range() {
+		let filter = LogFilterRequest {
+			address: Topic::This(address(0x11)),
+			from_block: Some(BlockNumber::Number(436)),
+			to_block: Some(BlockNumber::Latest),
+			block_hash: None,
+			topics: TopicFilter { topic0: Topic::This(hash(0xaa)), ..Default::default() },
+		};
+
+		let expected = r#"{"address":"0x1111111111111111111111111111111111111111","fromBlock":"0x1b4","toBlock":"latest","topics":["0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",null,null,null]}"#;
+		assert_eq!(serde_json::to_string(&filter).unwrap(), expected);
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serialize_block_hash() {
+		let mut filter = LogFilterRequest {
+			address: Topic::OneOf(vec![address(0x11), address(0x22)]),
+			block_hash: Some(hash(0xbb)),
+			..Default::default()
+		};
+
+		let expected = r#"{"address":["0x1111111111111111111111111111111111111111","0x2222222222222222222222222222222222222222"],"blockHash":"0xbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb","topics":[null,null,null,null]}"#;
+		assert_eq!(serde_json::to_string(&filter).unwrap(), expected);
+
+		filter.from_block = Some(BlockNumber::Earliest);
+		assert!(serde_json::to_string(&filter).is_err());
+	}
+
+	#[test]
+	fn matches_address_and_range() {
+		let filter = LogFilterRequest {
+			address: Topic::OneOf(vec![address(0x11), address(0x22)]),
+			from_block: Some(10.into()),
+			to_block: Some(BlockNumber::Latest),
+			..Default::default()
+		};
+
+		assert!(filter.matches(&entry(0x11, 10)));
+		assert!(filter.matches(&entry(0x22, 1000)));
+		assert!(!filter.matches(&entry(0x33, 10)));
+		assert!(!filter.matches(&entry(0x11, 9)));
+		assert!(!filter.matches(&(address(0x11), RawLog { topics: vec![], data: vec![] }).into()));
+	}
+
+	#[test]
+	fn matches_block_hash_and_topics() {
+		let mut filter = LogFilterRequest {
+			block_hash: Some(hash(0xbb)),
+			topics: TopicFilter { topic0: Topic::This(hash(0xaa)), ..Default::default() },
+			..Default::default()
+		};
+		assert!(filter.matches(&entry(0x11, 1)));
+
+		filter.block_hash = Some(hash(0xcc));
+		assert!(!filter.matches(&entry(0x11, 1)));
+
+		filter.block_hash = None;
+		filter.topics.topic0 = Topic::This(hash(0xcc));
+		assert!(!filter.matches(&entry(0x11, 1)));
+	}
+}
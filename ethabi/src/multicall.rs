@@ -0,0 +1,253 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [Multicall3](https://github.com/mds1/multicall) call aggregation.
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{
+	errors, Address, Bytes, Contract, Error, Function, Param, ParamType, Revert, StateMutability, Token, TupleParam,
+};
+
+/// Address of the Multicall3 contract, identical on all chains it is deployed to.
+pub const MULTICALL3_ADDRESS: Address = ethereum_types::H160([
+	0xca, 0x11, 0xbd, 0xe0, 0x59, 0x77, 0xb3, 0x63, 0x11, 0x67, 0x02, 0x88, 0x62, 0xbe, 0x2a, 0x17, 0x39, 0x76, 0xca,
+	0x11,
+]);
+
+/// Result of a single aggregated call, either its decoded outputs or the reason it reverted.
+///
+/// A successful call whose return data does not decode, e.g. a call to an account without code,
+/// is a [`Revert::Unknown`] with that data.
+pub type CallResult = Result<Vec<Token>, Revert>;
+
+/// Single call of an aggregation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Call<'a> {
+	/// Called contract.
+	pub target: Address,
+	/// Called function.
+	pub function: &'a Function,
+	/// Function input.
+	pub tokens: Vec<Token>,
+	/// Whether the whole aggregation may succeed if this call reverts.
+	pub allow_failure: bool,
+}
+
+/// Batch of calls aggregated through Multicall3 `aggregate3`.
+#[derive(Debug, Clone, Default)]
+pub struct Multicall<'a> {
+	calls: Vec<Call<'a>>,
+	errors: Option<&'a Contract>,
+}
+
+impl<'a> Multicall<'a> {
+	/// Creates an aggregation of `(target, function, input)` calls, each allowed to fail.
+	pub fn new<I>(calls: I) -> Self
+	where
+		I: IntoIterator<Item = (Address, &'a Function, Vec<Token>)>,
+	{
+		let calls = calls
+			.into_iter()
+			.map(|(target, function, tokens)| Call { target, function, tokens, allow_failure: true })
+			.collect();
+		Multicall { calls, errors: None }
+	}
+
+	/// Adds a call to the aggregation.
+	pub fn push(&mut self, call: Call<'a>) {
+		self.calls.push(call);
+	}
+
+	/// Decodes custom errors of reverted calls using the errors of `contract`.
+	pub fn with_errors(mut self, contract: &'a Contract) -> Self {
+		self.errors = Some(contract);
+		self
+	}
+
+	/// Returns aggregated calls.
+	pub fn calls(&self) -> &[Call<'a>] {
+		&self.calls
+	}
+
+	/// Prepares the `aggregate3` call data.
+	pub fn encode_input(&self) -> errors::Result<Bytes> {
+		let calls = self
+			.calls
+			.iter()
+			.map(|call| {
+				let data = call.function.encode_input(&call.tokens)?;
+				Ok(Token::Tuple(vec![Token::Address(call.target), Token::Bool(call.allow_failure), Token::Bytes(data)]))
+			})
+			.collect::<errors::Result<Vec<_>>>()?;
+
+		aggregate3().encode_input(&[Token::Array(calls)])
+	}
+
+	/// Parses the `aggregate3` output into per call results.
+	///
+	/// Outputs of successful calls are decoded using the outputs of the called function, revert
+	/// data of failed calls using the builtin errors and the errors given to
+	/// [`Multicall::with_errors`]. Only malformed `aggregate3` output fails the whole batch.
+	pub fn decode_output(&self, data: &[u8]) -> errors::Result<Vec<CallResult>> {
+		let results = aggregate3()
+			.decode_output(data)?
+			.into_iter()
+			.next()
+			.and_then(Token::into_array)
+			.ok_or(Error::InvalidData)?;
+
+		if results.len() != self.calls.len() {
+			return Err(Error::InvalidData);
+		}
+
+		results
+			.into_iter()
+			.zip(&self.calls)
+			.map(|(result, call)| {
+				let mut result = result.into_tuple().ok_or(Error::InvalidData)?.into_iter();
+				let success = result.next().and_then(Token::into_bool).ok_or(Error::InvalidData)?;
+				let data = result.next().and_then(Token::into_bytes).ok_or(Error::InvalidData)?;
+
+				match success {
+					true => Ok(call.function.decode_output(&data).map_err(|_| Revert::Unknown(data))),
+					false => {
+						let errors = self.errors.into_iter().flat_map(Contract::errors);
+						Ok(Err(Revert::decode_with_errors(&data, errors)))
+					}
+				}
+			})
+			.collect()
+	}
+}
+
+/// Returns the Multicall3 `aggregate3((address,bool,bytes)[])` function.
+pub fn aggregate3() -> Function {
+	let call = ParamType::Tuple(vec![ParamType::Address, ParamType::Bool, ParamType::Bytes]);
+	let result = ParamType::Tuple(vec![ParamType::Bool, ParamType::Bytes]);
+
+	#[allow(deprecated)]
+	Function {
+		name: "aggregate3".to_owned(),
+		inputs: vec![Param {
+			name: "calls".to_owned(),
+			kind: ParamType::Array(Box::new(call)),
+			internal_type: Some("struct Multicall3.Call3[]".to_owned()),
+			components: components(&[
+				("target", ParamType::Address),
+				("allowFailure", ParamType::Bool),
+				("callData", ParamType::Bytes),
+			]),
+		}],
+		outputs: vec![Param {
+			name: "returnData".to_owned(),
+			kind: ParamType::Array(Box::new(result)),
+			internal_type: Some("struct Multicall3.Result[]".to_owned()),
+			components: components(&[("success", ParamType::Bool), ("returnData", ParamType::Bytes)]),
+		}],
+		constant: None,
+		state_mutability: StateMutability::Payable,
+	}
+}
+
+fn components(params: &[(&str, ParamType)]) -> Vec<TupleParam> {
+	params.iter().map(|(name, kind)| TupleParam::new(*name, kind.clone())).collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use hex_literal::hex;
+
+	use super::{aggregate3, Multicall};
+	#[cfg(not(feature = "std"))]
+	use crate::no_std_prelude::*;
+	use crate::{encode, Address, Function, Param, ParamType, Revert, StateMutability, Token};
+
+	fn balance_of() -> Function {
+		#[allow(deprecated)]
+		Function {
+			name: "balanceOf".to_owned(),
+			inputs: vec![Param {
+				name: "owner".to_owned(),
+				kind: ParamType::Address,
+				internal_type: None,
+				components: vec![],
+			}],
+			outputs: vec![Param {
+				name: "".to_owned(),
+				kind: ParamType::Uint(256),
+				internal_type: None,
+				components: vec![],
+			}],
+			constant: None,
+			state_mutability: StateMutability::View,
+		}
+	}
+
+	#[test]
+	fn aggregate3_selector() {
+		assert_eq!(aggregate3().short_signature(), hex!("82ad56cb"));
+	}
+
+	#[test]
+	fn encode_input() {
+		let function = balance_of();
+		let token: Address = [0x11u8; 20].into();
+		let owner = Token::Address([0x22u8; 20].into());
+		let multicall = Multicall::new(vec![(token, &function, vec![owner.clone()])]);
+
+		let encoded = multicall.encode_input().unwrap();
+		let expected = aggregate3()
+			.encode_input(&[Token::Array(vec![Token::Tuple(vec![
+				Token::Address(token),
+				Token::Bool(true),
+				Token::Bytes(function.encode_input(&[owner]).unwrap()),
+			])])])
+			.unwrap();
+		assert_eq!(encoded, expected);
+		assert_eq!(&encoded[..4], hex!("82ad56cb"));
+	}
+
+	#[test]
+	fn encode_input_checks_types() {
+		let function = balance_of();
+		let multicall = Multicall::new(vec![([0x11u8; 20].into(), &function, vec![Token::Bool(true)])]);
+		assert!(multicall.encode_input().is_err());
+	}
+
+	#[test]
+	fn decode_output() {
+		let function = balance_of();
+		let owner = Token::Address([0x22u8; 20].into());
+		let multicall = Multicall::new(vec![
+			([0x11u8; 20].into(), &function, vec![owner.clone()]),
+			([0x33u8; 20].into(), &function, vec![owner]),
+		]);
+
+		let revert = hex!("4e487b710000000000000000000000000000000000000000000000000000000000000001").to_vec();
+		let output = encode(&[Token::Array(vec![
+			Token::Tuple(vec![Token::Bool(true), Token::Bytes(encode(&[Token::Uint(1000.into())]))]),
+			Token::Tuple(vec![Token::Bool(false), Token::Bytes(revert)]),
+		])]);
+
+		let results = multicall.decode_output(&output).unwrap();
+		assert_eq!(results, vec![Ok(vec![Token::Uint(1000.into())]), Err(Revert::Panic(1.into()))]);
+
+		// successful calls to accounts without code return no data
+		let output = encode(&[Token::Array(vec![
+			Token::Tuple(vec![Token::Bool(true), Token::Bytes(vec![])]),
+			Token::Tuple(vec![Token::Bool(true), Token::Bytes(encode(&[Token::Uint(7.into())]))]),
+		])]);
+		let results = multicall.decode_output(&output).unwrap();
+		assert_eq!(results, vec![Err(Revert::Unknown(vec![])), Ok(vec![Token::Uint(7.into())])]);
+
+		// result count has to match the number of calls
+		let output = encode(&[Token::Array(vec![])]);
+		assert!(multicall.decode_output(&output).is_err());
+	}
+}
@@ -0,0 +1,160 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Decoded tokens paired with the names of their params.
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{Param, ParamType, Token, TupleParam};
+
+/// Decoded value of a param, with tuples broken down into named components.
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecodedValue {
+	/// Value which is neither a tuple nor an array.
+	Token(Token),
+	/// Elements of an array or fixed array.
+	Array(Vec<DecodedValue>),
+	/// Components of a tuple.
+	Tuple(Vec<NamedToken>),
+}
+
+/// Decoded value together with the name and internal type of its param.
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamedToken {
+	/// Param name, empty for unnamed params.
+	pub name: String,
+	/// Additional internal type.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub internal_type: Option<String>,
+	/// Decoded value.
+	pub value: DecodedValue,
+}
+
+impl NamedToken {
+	/// Pairs `tokens` with `params`, which are expected to be the params they were decoded with.
+	pub fn from_params(params: &[Param], tokens: Vec<Token>) -> Vec<NamedToken> {
+		params
+			.iter()
+			.zip(tokens)
+			.map(|(param, token)| NamedToken {
+				name: param.name.clone(),
+				internal_type: param.internal_type.clone(),
+				value: DecodedValue::new(&param.kind, &param.components, token),
+			})
+			.collect()
+	}
+}
+
+impl DecodedValue {
+	/// Names the components of `token` of the given type, `components` describing the tuple of a tuple
+	/// or array type.
+	fn new(kind: &ParamType, components: &[TupleParam], token: Token) -> Self {
+		match (kind, token) {
+			(ParamType::Tuple(kinds), Token::Tuple(tokens)) => {
+				let named = tokens
+					.into_iter()
+					.zip(kinds)
+					.enumerate()
+					.map(|(i, (token, kind))| {
+						let component = components.get(i);
+						NamedToken {
+							name: component.and_then(|c| c.name.clone()).unwrap_or_default(),
+							internal_type: component.and_then(|c| c.internal_type.clone()),
+							value: DecodedValue::new(kind, component.map_or(&[], |c| &c.components), token),
+						}
+					})
+					.collect();
+				DecodedValue::Tuple(named)
+			}
+			(ParamType::Array(kind), Token::Array(tokens))
+			| (ParamType::FixedArray(kind, _), Token::FixedArray(tokens)) => DecodedValue::Array(
+				tokens.into_iter().map(|token| DecodedValue::new(kind, components, token)).collect(),
+			),
+			(_, token) => DecodedValue::Token(token),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{DecodedValue, NamedToken};
+	#[cfg(not(feature = "std"))]
+	use crate::no_std_prelude::*;
+	use crate::{Param, ParamType, Token, TupleParam};
+
+	fn component(name: &str, kind: ParamType) -> TupleParam {
+		TupleParam { name: Some(name.to_owned()), kind, internal_type: None, components: vec![] }
+	}
+
+	fn orders() -> Param {
+		let order = vec![ParamType::Address, ParamType::Uint(256)];
+		Param {
+			name: "orders".to_owned(),
+			kind: ParamType::Array(Box::new(ParamType::Tuple(order))),
+			internal_type: Some("struct Order[]".to_owned()),
+			components: vec![component("maker", ParamType::Address), component("amount", ParamType::Uint(256))],
+		}
+	}
+
+	#[test]
+	fn from_params() {
+		let order = Token::Tuple(vec![Token::Address([0x11u8; 20].into()), Token::Uint(5.into())]);
+		let named = NamedToken::from_params(&[orders()], vec![Token::Array(vec![order])]);
+
+		let expected = vec![NamedToken {
+			name: "orders".to_owned(),
+			internal_type: Some("struct Order[]".to_owned()),
+			value: DecodedValue::Array(vec![DecodedValue::Tuple(vec![
+				NamedToken {
+					name: "maker".to_owned(),
+					internal_type: None,
+					value: DecodedValue::Token(Token::Address([0x11u8; 20].into())),
+				},
+				NamedToken {
+					name: "amount".to_owned(),
+					internal_type: None,
+					value: DecodedValue::Token(Token::Uint(5.into())),
+				},
+			])]),
+		}];
+		assert_eq!(named, expected);
+	}
+
+	#[test]
+	fn from_params_without_components() {
+		let mut param = orders();
+		param.components.clear();
+
+		let order = Token::Tuple(vec![Token::Address([0x11u8; 20].into()), Token::Uint(5.into())]);
+		let named = NamedToken::from_params(&[param], vec![Token::Array(vec![order])]);
+		match named[0].value {
+			DecodedValue::Array(ref orders) => match orders[0] {
+				DecodedValue::Tuple(ref components) => assert_eq!(components[1].name, ""),
+				ref other => panic!("unexpected value {other:?}"),
+			},
+			ref other => panic!("unexpected value {other:?}"),
+		}
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serialize() {
+		let order = Token::Tuple(vec![Token::Address([0x11u8; 20].into()), Token::Uint(5.into())]);
+		let named = NamedToken::from_params(&[orders()], vec![Token::Array(vec![order])]);
+
+		let expected = r#"[{"name":"orders","internalType":"struct Order[]","value":[[{"name":"maker","value":{"Address":"0x1111111111111111111111111111111111111111"}},{"name":"amount","value":{"Uint":"0x5"}}]]}]"#;
+		assert_eq!(serde_json::to_string(&named).unwrap(), expected);
+	}
+}
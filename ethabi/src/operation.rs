@@ -40,7 +40,7 @@ mod tests {
 	use super::Operation;
 	#[cfg(not(feature = "std"))]
 	use crate::no_std_prelude::*;
-	use crate::{tests::assert_ser_de, Event, EventParam, Function, Param, ParamType, StateMutability};
+	use crate::{tests::assert_ser_de, Event, EventParam, Function, Param, ParamType, StateMutability, TupleParam};
 
 	#[test]
 	fn operation() {
@@ -59,7 +59,12 @@ mod tests {
 		#[allow(deprecated)]
 		let function = Function {
 			name: "foo".to_owned(),
-			inputs: vec![Param { name: "a".to_owned(), kind: ParamType::Address, internal_type: None }],
+			inputs: vec![Param {
+				name: "a".to_owned(),
+				kind: ParamType::Address,
+				internal_type: None,
+				components: vec![],
+			}],
 			outputs: vec![],
 			constant: None,
 			state_mutability: StateMutability::NonPayable,
@@ -115,7 +120,7 @@ mod tests {
 			Operation::Event(Event {
 				name: "E".to_owned(),
 				inputs: vec![
-					EventParam { name: "a".to_owned(), kind: ParamType::Address, indexed: true },
+					EventParam { name: "a".to_owned(), kind: ParamType::Address, indexed: true, components: vec![] },
 					EventParam {
 						name: "b".to_owned(),
 						kind: ParamType::Array(Box::new(ParamType::Tuple(vec![
@@ -123,7 +128,18 @@ mod tests {
 							ParamType::Uint(256),
 							ParamType::Bytes
 						]))),
-						indexed: false
+						indexed: false,
+						components: [
+							("to", ParamType::Address),
+							("value", ParamType::Uint(256)),
+							("data", ParamType::Bytes)
+						]
+						.into_iter()
+						.map(|(name, kind)| TupleParam {
+							internal_type: Some(kind.to_string()),
+							..TupleParam::new(name, kind)
+						})
+						.collect(),
 					},
 				],
 				anonymous: false,
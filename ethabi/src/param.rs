@@ -20,9 +20,9 @@ use serde::{
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-use crate::ParamType;
 #[cfg(feature = "serde")]
-use crate::{param_type::Writer, TupleParam};
+use crate::param_type::Writer;
+use crate::{ParamType, TupleParam};
 
 /// Function param.
 #[derive(Debug, Clone, PartialEq)]
@@ -33,6 +33,21 @@ pub struct Param {
 	pub kind: ParamType,
 	/// Additional Internal type.
 	pub internal_type: Option<String>,
+	/// Components of a tuple param or of the tuples of an array param, empty if unknown.
+	pub components: Vec<TupleParam>,
+}
+
+impl Param {
+	/// Creates a param without internal type and components.
+	pub fn new<S: Into<String>>(name: S, kind: ParamType) -> Self {
+		Param { name: name.into(), kind, internal_type: None, components: Vec::new() }
+	}
+
+	/// Sets the components of a tuple param or of the tuples of an array param.
+	pub fn with_components(mut self, components: Vec<TupleParam>) -> Self {
+		self.components = components;
+		self
+	}
 }
 
 #[cfg(feature = "serde")]
@@ -97,8 +112,8 @@ impl<'a> Visitor<'a> for ParamVisitor {
 		}
 		let name = name.ok_or_else(|| Error::missing_field("name"))?;
 		let mut kind = kind.ok_or_else(|| Error::missing_field("kind"))?;
-		set_tuple_components::<V::Error>(&mut kind, components)?;
-		Ok(Param { name, kind, internal_type })
+		let components = set_tuple_components::<V::Error>(&mut kind, components)?;
+		Ok(Param { name, kind, internal_type, components })
 	}
 }
 
@@ -114,14 +129,29 @@ impl Serialize for Param {
 		}
 		map.serialize_entry("name", &self.name)?;
 		map.serialize_entry("type", &Writer::write_for_abi(&self.kind, false))?;
-		if let Some(inner_tuple) = inner_tuple(&self.kind) {
-			map.serialize_key("components")?;
-			map.serialize_value(&SerializeableParamVec(inner_tuple))?;
-		}
+		serialize_components(&mut map, &self.kind, &self.components)?;
 		map.end()
 	}
 }
 
+/// Serializes the `components` of a tuple param, falling back to its unnamed types if the
+/// components are unknown.
+#[cfg(feature = "serde")]
+pub(crate) fn serialize_components<M: SerializeMap>(
+	map: &mut M,
+	kind: &ParamType,
+	components: &[TupleParam],
+) -> Result<(), M::Error> {
+	if let Some(inner_tuple) = inner_tuple(kind) {
+		map.serialize_key("components")?;
+		match components.is_empty() {
+			true => map.serialize_value(&SerializeableParamVec(inner_tuple))?,
+			false => map.serialize_value(components)?,
+		}
+	}
+	Ok(())
+}
+
 #[cfg(feature = "serde")]
 pub(crate) fn inner_tuple_mut(mut param: &mut ParamType) -> Option<&mut Vec<ParamType>> {
 	loop {
@@ -150,12 +180,15 @@ pub(crate) fn inner_tuple(mut param: &ParamType) -> Option<&Vec<ParamType>> {
 pub(crate) fn set_tuple_components<Error: serde::de::Error>(
 	kind: &mut ParamType,
 	components: Option<Vec<TupleParam>>,
-) -> Result<(), Error> {
-	if let Some(inner_tuple_mut) = inner_tuple_mut(kind) {
-		let tuple_params = components.ok_or_else(|| Error::missing_field("components"))?;
-		inner_tuple_mut.extend(tuple_params.into_iter().map(|param| param.kind))
+) -> Result<Vec<TupleParam>, Error> {
+	match inner_tuple_mut(kind) {
+		Some(inner_tuple_mut) => {
+			let tuple_params = components.ok_or_else(|| Error::missing_field("components"))?;
+			inner_tuple_mut.extend(tuple_params.iter().map(|param| param.kind.clone()));
+			Ok(tuple_params)
+		}
+		None => Ok(Vec::new()),
 	}
-	Ok(())
 }
 
 #[cfg(feature = "serde")]
@@ -200,9 +233,18 @@ mod tests {
 	use crate::no_std_prelude::*;
 	use crate::{
 		tests::{assert_json_eq, assert_ser_de},
-		Param, ParamType,
+		Param, ParamType, TupleParam,
 	};
 
+	#[test]
+	fn constructors() {
+		let kind = ParamType::Tuple(vec![ParamType::Bool]);
+		let param = Param::new("a", kind.clone()).with_components(vec![TupleParam::new("b", ParamType::Bool)]);
+		let component =
+			TupleParam { name: Some("b".to_owned()), kind: ParamType::Bool, internal_type: None, components: vec![] };
+		assert_eq!(param, Param { name: "a".to_owned(), kind, internal_type: None, components: vec![component] });
+	}
+
 	#[test]
 	fn param_simple() {
 		let s = r#"{
@@ -212,7 +254,10 @@ mod tests {
 
 		let deserialized: Param = serde_json::from_str(s).unwrap();
 
-		assert_eq!(deserialized, Param { name: "foo".to_owned(), kind: ParamType::Address, internal_type: None });
+		assert_eq!(
+			deserialized,
+			Param { name: "foo".to_owned(), kind: ParamType::Address, internal_type: None, components: vec![] }
+		);
 
 		assert_json_eq(s, serde_json::to_string(&deserialized).unwrap().as_str());
 	}
@@ -232,7 +277,8 @@ mod tests {
 			Param {
 				name: "foo".to_owned(),
 				kind: ParamType::Address,
-				internal_type: Some("struct Verifier.Proof".to_string())
+				internal_type: Some("struct Verifier.Proof".to_string()),
+				components: vec![]
 			}
 		);
 
@@ -266,7 +312,21 @@ mod tests {
 			Param {
 				name: "foo".to_owned(),
 				kind: ParamType::Tuple(vec![ParamType::Uint(48), ParamType::Tuple(vec![ParamType::Address])]),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					TupleParam { name: None, kind: ParamType::Uint(48), internal_type: None, components: vec![] },
+					TupleParam {
+						name: None,
+						kind: ParamType::Tuple(vec![ParamType::Address]),
+						internal_type: None,
+						components: vec![TupleParam {
+							name: None,
+							kind: ParamType::Address,
+							internal_type: None,
+							components: vec![]
+						}]
+					}
+				]
 			}
 		);
 
@@ -301,7 +361,21 @@ mod tests {
 			Param {
 				name: "foo".to_owned(),
 				kind: ParamType::Tuple(vec![ParamType::Uint(48), ParamType::Tuple(vec![ParamType::Address])]),
-				internal_type: Some("struct Pairing.G1Point[]".to_string())
+				internal_type: Some("struct Pairing.G1Point[]".to_string()),
+				components: vec![
+					TupleParam { name: None, kind: ParamType::Uint(48), internal_type: None, components: vec![] },
+					TupleParam {
+						name: None,
+						kind: ParamType::Tuple(vec![ParamType::Address]),
+						internal_type: None,
+						components: vec![TupleParam {
+							name: None,
+							kind: ParamType::Address,
+							internal_type: None,
+							components: vec![]
+						}]
+					}
+				]
 			}
 		);
 
@@ -338,7 +412,26 @@ mod tests {
 			Param {
 				name: "foo".to_owned(),
 				kind: ParamType::Tuple(vec![ParamType::Uint(48), ParamType::Tuple(vec![ParamType::Address])]),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					TupleParam {
+						name: Some("amount".to_owned()),
+						kind: ParamType::Uint(48),
+						internal_type: None,
+						components: vec![]
+					},
+					TupleParam {
+						name: Some("things".to_owned()),
+						kind: ParamType::Tuple(vec![ParamType::Address]),
+						internal_type: None,
+						components: vec![TupleParam {
+							name: Some("baseTupleParam".to_owned()),
+							kind: ParamType::Address,
+							internal_type: None,
+							components: vec![]
+						}]
+					}
+				]
 			}
 		);
 
@@ -374,7 +467,12 @@ mod tests {
 					ParamType::Address,
 					ParamType::Address
 				]))),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					TupleParam { name: None, kind: ParamType::Uint(48), internal_type: None, components: vec![] },
+					TupleParam { name: None, kind: ParamType::Address, internal_type: None, components: vec![] },
+					TupleParam { name: None, kind: ParamType::Address, internal_type: None, components: vec![] }
+				]
 			}
 		);
 
@@ -405,7 +503,11 @@ mod tests {
 					ParamType::Uint(8),
 					ParamType::Uint(16),
 				]))))),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					TupleParam { name: None, kind: ParamType::Uint(8), internal_type: None, components: vec![] },
+					TupleParam { name: None, kind: ParamType::Uint(16), internal_type: None, components: vec![] }
+				]
 			}
 		);
 
@@ -440,7 +542,12 @@ mod tests {
 					Box::new(ParamType::Tuple(vec![ParamType::Uint(48), ParamType::Address, ParamType::Address])),
 					2
 				),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					TupleParam { name: None, kind: ParamType::Uint(48), internal_type: None, components: vec![] },
+					TupleParam { name: None, kind: ParamType::Address, internal_type: None, components: vec![] },
+					TupleParam { name: None, kind: ParamType::Address, internal_type: None, components: vec![] }
+				]
 			}
 		);
 
@@ -482,7 +589,31 @@ mod tests {
 					ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Address]))),
 					ParamType::FixedArray(Box::new(ParamType::Tuple(vec![ParamType::Address])), 42,)
 				]),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					TupleParam {
+						name: None,
+						kind: ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Address]))),
+						internal_type: None,
+						components: vec![TupleParam {
+							name: None,
+							kind: ParamType::Address,
+							internal_type: None,
+							components: vec![]
+						}]
+					},
+					TupleParam {
+						name: None,
+						kind: ParamType::FixedArray(Box::new(ParamType::Tuple(vec![ParamType::Address])), 42),
+						internal_type: None,
+						components: vec![TupleParam {
+							name: None,
+							kind: ParamType::Address,
+							internal_type: None,
+							components: vec![]
+						}]
+					}
+				]
 			}
 		);
 
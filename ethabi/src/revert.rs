@@ -0,0 +1,176 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Revert data decoding.
+
+use core::fmt;
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{decode, error::Error as AbiError, Bytes, ParamType, Token, Uint};
+
+/// Selector of the builtin `Error(string)` error.
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Selector of the builtin `Panic(uint256)` error.
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Decoded revert data of a failed call.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Revert {
+	/// `require(false, reason)` or `revert(reason)`.
+	Error(String),
+	/// Failed assertion or other runtime error, with its panic code.
+	Panic(Uint),
+	/// Custom error and its decoded params.
+	Custom(AbiError, Vec<Token>),
+	/// Revert data which could not be decoded, empty for `revert()`.
+	Unknown(Bytes),
+}
+
+impl Revert {
+	/// Decodes revert data, recognizing the builtin `Error(string)` and `Panic(uint256)` errors.
+	pub fn decode(data: &[u8]) -> Self {
+		Self::decode_with_errors(data, None)
+	}
+
+	/// Decodes revert data, recognizing the builtin errors and the given custom `errors`.
+	pub fn decode_with_errors<'a, I>(data: &[u8], errors: I) -> Self
+	where
+		I: IntoIterator<Item = &'a AbiError>,
+	{
+		if data.len() < 4 {
+			return Revert::Unknown(data.to_vec());
+		}
+		let (selector, params) = data.split_at(4);
+
+		let builtin = match selector {
+			s if s == ERROR_SELECTOR => decode(&[ParamType::String], params)
+				.ok()
+				.and_then(|tokens| tokens.into_iter().next())
+				.and_then(Token::into_string)
+				.map(Revert::Error),
+			s if s == PANIC_SELECTOR => decode(&[ParamType::Uint(256)], params)
+				.ok()
+				.and_then(|tokens| tokens.into_iter().next())
+				.and_then(Token::into_uint)
+				.map(Revert::Panic),
+			_ => None,
+		};
+		if let Some(revert) = builtin {
+			return revert;
+		}
+
+		errors
+			.into_iter()
+			.filter(|error| error.signature()[..4] == *selector)
+			.find_map(|error| error.decode(params).ok().map(|tokens| Revert::Custom(error.clone(), tokens)))
+			.unwrap_or_else(|| Revert::Unknown(data.to_vec()))
+	}
+
+	/// Describes the cause of a `Panic`, as documented by Solidity for its panic codes.
+	pub fn panic_reason(&self) -> Option<&'static str> {
+		let code = match *self {
+			Revert::Panic(code) if code <= Uint::from(u8::MAX) => code.low_u32(),
+			_ => return None,
+		};
+		let reason = match code {
+			0x00 => "generic compiler inserted panic",
+			0x01 => "assertion failed",
+			0x11 => "arithmetic overflow or underflow",
+			0x12 => "division or modulo by zero",
+			0x21 => "conversion to an invalid enum value",
+			0x22 => "access to an incorrectly encoded storage byte array",
+			0x31 => "pop on an empty array",
+			0x32 => "array index out of bounds",
+			0x41 => "out of memory or too large allocation",
+			0x51 => "call to an uninitialized internal function",
+			_ => return None,
+		};
+		Some(reason)
+	}
+}
+
+impl fmt::Display for Revert {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			Revert::Error(ref reason) => write!(f, "Error({reason})"),
+			Revert::Panic(ref code) => write!(f, "Panic({code:#x})"),
+			Revert::Custom(ref error, ref tokens) => {
+				let tokens = tokens.iter().map(|t| format!("{t}")).collect::<Vec<String>>().join(",");
+				write!(f, "{}({tokens})", error.name)
+			}
+			Revert::Unknown(ref data) => write!(f, "0x{}", hex::encode(data)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use hex_literal::hex;
+
+	use super::Revert;
+	#[cfg(not(feature = "std"))]
+	use crate::no_std_prelude::*;
+	use crate::{AbiError, Param, ParamType, Token, Uint};
+
+	#[test]
+	fn decode_error() {
+		let data = hex!(
+			"
+			08c379a0
+			0000000000000000000000000000000000000000000000000000000000000020
+			0000000000000000000000000000000000000000000000000000000000000004
+			6e6f706500000000000000000000000000000000000000000000000000000000
+			"
+		);
+		assert_eq!(Revert::decode(&data), Revert::Error("nope".to_owned()));
+	}
+
+	#[test]
+	fn decode_panic() {
+		let data = hex!("4e487b710000000000000000000000000000000000000000000000000000000000000011");
+		assert_eq!(Revert::decode(&data), Revert::Panic(0x11.into()));
+		assert_eq!(Revert::decode(&data).to_string(), "Panic(0x11)");
+		assert_eq!(Revert::decode(&data).panic_reason(), Some("arithmetic overflow or underflow"));
+	}
+
+	#[test]
+	fn panic_reason() {
+		assert_eq!(Revert::Panic(0x01.into()).panic_reason(), Some("assertion failed"));
+		assert_eq!(Revert::Panic(0x02.into()).panic_reason(), None);
+		assert_eq!(Revert::Panic(Uint::MAX).panic_reason(), None);
+		assert_eq!(Revert::Error("0x01".to_owned()).panic_reason(), None);
+	}
+
+	#[test]
+	fn decode_custom() {
+		let error = AbiError {
+			name: "Unauthorized".to_owned(),
+			inputs: vec![Param {
+				name: "user".to_owned(),
+				kind: ParamType::Address,
+				internal_type: None,
+				components: vec![],
+			}],
+		};
+		let data = error.encode(&[Token::Address([0x11u8; 20].into())]).unwrap();
+
+		assert_eq!(Revert::decode(&data), Revert::Unknown(data.clone()));
+		assert_eq!(
+			Revert::decode_with_errors(&data, [&error]),
+			Revert::Custom(error.clone(), vec![Token::Address([0x11u8; 20].into())])
+		);
+	}
+
+	#[test]
+	fn decode_unknown() {
+		assert_eq!(Revert::decode(&[]), Revert::Unknown(vec![]));
+		assert_eq!(Revert::decode(&hex!("08c379a0")), Revert::Unknown(hex!("08c379a0").to_vec()));
+	}
+}
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 
 /// Whether a function modifies or reads blockchain state
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
 pub enum StateMutability {
 	/// Specified not to read blockchain state
 	#[cfg_attr(feature = "serde", serde(rename = "pure"))]
@@ -12,6 +12,7 @@ pub enum StateMutability {
 	#[cfg_attr(feature = "serde", serde(rename = "view"))]
 	View,
 	/// Function does not accept Ether - the default
+	#[default]
 	#[cfg_attr(feature = "serde", serde(rename = "nonpayable"))]
 	NonPayable,
 	/// Function accepts Ether
@@ -19,12 +20,6 @@ pub enum StateMutability {
 	Payable,
 }
 
-impl Default for StateMutability {
-	fn default() -> Self {
-		Self::NonPayable
-	}
-}
-
 #[cfg(all(test, feature = "serde"))]
 mod test {
 	#[cfg(not(feature = "std"))]
@@ -0,0 +1,261 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Solidity storage layout slot computation.
+//!
+//! Slots follow the [layout of state variables in storage](https://docs.soliditylang.org/en/latest/internals/layout_in_storage.html).
+
+use sha3::{Digest, Keccak256};
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{encode, encode_packed, Error, ParamType, Result, Token, Uint};
+
+/// Position of a value in contract storage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageLocation {
+	/// Storage slot.
+	pub slot: Uint,
+	/// Offset in bytes from the lower-order (right) end of the slot, non-zero for packed values.
+	pub offset: usize,
+}
+
+impl StorageLocation {
+	/// Location at the start of `slot`.
+	pub fn new(slot: Uint) -> Self {
+		StorageLocation { slot, offset: 0 }
+	}
+}
+
+fn keccak(data: &[u8]) -> Uint {
+	Uint::from_big_endian(&Keccak256::digest(data))
+}
+
+/// Returns the slot of `mapping[key]` for a mapping stored at `slot`.
+///
+/// Value type keys are padded to 32 bytes, `string` and `bytes` keys are hashed unpadded.
+pub fn mapping_slot(slot: Uint, key: &Token) -> Result<Uint> {
+	let data = match *key {
+		Token::Bytes(_) => encode_packed(&[ParamType::Bytes, ParamType::Uint(256)], &[key.clone(), Token::Uint(slot)])?,
+		Token::String(_) => {
+			encode_packed(&[ParamType::String, ParamType::Uint(256)], &[key.clone(), Token::Uint(slot)])?
+		}
+		Token::Array(_) | Token::FixedArray(_) | Token::Tuple(_) | Token::HashedTopic(_) => {
+			return Err(Error::InvalidData)
+		}
+		_ => encode(&[key.clone(), Token::Uint(slot)]),
+	};
+	Ok(keccak(&data))
+}
+
+/// Returns the slot of `mapping[keys[0]][keys[1]]...` for nested mappings stored at `slot`.
+pub fn nested_mapping_slot(slot: Uint, keys: &[Token]) -> Result<Uint> {
+	keys.iter().try_fold(slot, mapping_slot)
+}
+
+/// Returns the first slot of the elements of a dynamic array, or of the data of a long `string`
+/// or `bytes`, stored at `slot`.
+pub fn dynamic_array_slot(slot: Uint) -> Uint {
+	keccak(&encode(&[Token::Uint(slot)]))
+}
+
+/// Returns the location of element `index` of an array whose elements start at `base`.
+///
+/// For fixed size arrays `base` is the slot of the array, for dynamic arrays it is
+/// [`dynamic_array_slot`] of it. Slots wrap around modulo 2^256 like in Solidity.
+pub fn array_element(base: Uint, element: &ParamType, index: Uint) -> StorageLocation {
+	match value_size(element) {
+		Some(size) => {
+			let per_slot = Uint::from(32 / size);
+			let offset = (index % per_slot).as_usize() * size;
+			StorageLocation { slot: base.overflowing_add(index / per_slot).0, offset }
+		}
+		None => {
+			let slots = index.overflowing_mul(storage_slots(element).into()).0;
+			StorageLocation::new(base.overflowing_add(slots).0)
+		}
+	}
+}
+
+/// Returns the location of element `index` of a dynamic array stored at `slot`.
+pub fn dynamic_array_element(slot: Uint, element: &ParamType, index: Uint) -> StorageLocation {
+	array_element(dynamic_array_slot(slot), element, index)
+}
+
+/// Returns the locations of struct `fields` relative to the first slot of the struct.
+///
+/// `string`, `bytes` and dynamic array fields take a single slot holding their length.
+pub fn struct_layout(fields: &[ParamType]) -> Vec<StorageLocation> {
+	layout(fields).0
+}
+
+/// Returns the location of field `index` of a struct with `fields` stored at `slot`.
+pub fn struct_field(slot: Uint, fields: &[ParamType], index: usize) -> Option<StorageLocation> {
+	struct_layout(fields)
+		.get(index)
+		.map(|location| StorageLocation { slot: slot.overflowing_add(location.slot).0, offset: location.offset })
+}
+
+/// Returns the number of slots occupied by a value of the given type.
+pub fn storage_slots(kind: &ParamType) -> usize {
+	match *kind {
+		ParamType::FixedArray(ref element, len) => match value_size(element) {
+			Some(size) => len.div_ceil(32 / size),
+			None => len * storage_slots(element),
+		},
+		ParamType::Tuple(ref fields) => layout(fields).1,
+		_ => 1,
+	}
+}
+
+/// Returns the size in bytes of types which can be packed together with other values.
+fn value_size(kind: &ParamType) -> Option<usize> {
+	match *kind {
+		ParamType::Address => Some(20),
+		ParamType::Bool => Some(1),
+		ParamType::Int(size) | ParamType::Uint(size) => Some(size / 8),
+		ParamType::FixedBytes(size) => Some(size),
+		_ => None,
+	}
+}
+
+/// Lays out `fields` returning their relative locations and the number of occupied slots.
+fn layout(fields: &[ParamType]) -> (Vec<StorageLocation>, usize) {
+	let mut slot = 0usize;
+	let mut offset = 0usize;
+	let mut locations = Vec::with_capacity(fields.len());
+
+	for field in fields {
+		match value_size(field) {
+			Some(size) => {
+				if offset + size > 32 {
+					slot += 1;
+					offset = 0;
+				}
+				locations.push(StorageLocation { slot: slot.into(), offset });
+				offset += size;
+			}
+			// everything else starts a new slot and is followed by a new slot
+			None => {
+				if offset > 0 {
+					slot += 1;
+					offset = 0;
+				}
+				locations.push(StorageLocation::new(slot.into()));
+				slot += storage_slots(field);
+			}
+		}
+	}
+
+	if offset > 0 {
+		slot += 1;
+	}
+	(locations, slot)
+}
+
+#[cfg(test)]
+mod tests {
+	use hex_literal::hex;
+
+	use super::*;
+
+	fn uint(bytes: [u8; 32]) -> Uint {
+		Uint::from_big_endian(&bytes)
+	}
+
+	#[test]
+	fn mapping_slot_value_key() {
+		// keccak256(abi.encode(address(0x11..11), uint256(0)))
+		let key = Token::Address([0x11u8; 20].into());
+		let expected = uint(hex!("f043c50fe795c69f30b8ff78b84032dc53a9d87ca283ae10a1dacfbb648e83ef"));
+		assert_eq!(mapping_slot(0.into(), &key).unwrap(), expected);
+	}
+
+	#[test]
+	fn mapping_slot_string_key() {
+		// keccak256(abi.encodePacked("key", uint256(1)))
+		let key = Token::String("key".to_owned());
+		let expected = uint(hex!("27c5b2b30fba377256b485ddee54115a67d43520505078b28b8a52f8e5e4c564"));
+		assert_eq!(mapping_slot(1.into(), &key).unwrap(), expected);
+		assert_eq!(mapping_slot(1.into(), &Token::Bytes(b"key".to_vec())).unwrap(), expected);
+	}
+
+	#[test]
+	fn mapping_slot_invalid_key() {
+		assert!(mapping_slot(0.into(), &Token::Array(vec![])).is_err());
+		// the original key of a decoded indexed string is unknown
+		assert!(mapping_slot(0.into(), &Token::HashedTopic([0x11u8; 32].into())).is_err());
+	}
+
+	#[test]
+	fn nested_mapping() {
+		let owner = Token::Address([0x11u8; 20].into());
+		let spender = Token::Address([0x22u8; 20].into());
+		let expected = mapping_slot(mapping_slot(2.into(), &owner).unwrap(), &spender).unwrap();
+		assert_eq!(nested_mapping_slot(2.into(), &[owner, spender]).unwrap(), expected);
+		assert_eq!(nested_mapping_slot(2.into(), &[]).unwrap(), 2.into());
+	}
+
+	#[test]
+	fn dynamic_array() {
+		// keccak256(abi.encode(uint256(0)))
+		let base = uint(hex!("290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563"));
+		assert_eq!(dynamic_array_slot(0.into()), base);
+
+		assert_eq!(dynamic_array_element(0.into(), &ParamType::Uint(256), 3.into()), StorageLocation::new(base + 3));
+		assert_eq!(
+			dynamic_array_element(0.into(), &ParamType::Uint(64), 5.into()),
+			StorageLocation { slot: base + 1, offset: 8 }
+		);
+		let pair = ParamType::Tuple(vec![ParamType::Uint(256), ParamType::Address]);
+		assert_eq!(dynamic_array_element(0.into(), &pair, 2.into()), StorageLocation::new(base + 4));
+	}
+
+	#[test]
+	fn slots_wrap_around() {
+		let max = Uint::MAX;
+		assert_eq!(array_element(max, &ParamType::Uint(256), 2.into()), StorageLocation::new(1.into()));
+		let pair = ParamType::Tuple(vec![ParamType::Uint(256), ParamType::Address]);
+		assert_eq!(array_element(max - 1, &pair, max), StorageLocation::new(max - 3));
+		assert_eq!(array_element(max, &ParamType::Uint(128), 3.into()), StorageLocation { slot: 0.into(), offset: 16 });
+		// does not panic whatever the base
+		dynamic_array_element(0.into(), &ParamType::Uint(256), max);
+		assert_eq!(
+			struct_field(max, &[ParamType::Uint(256), ParamType::Bool], 1),
+			Some(StorageLocation::new(0.into()))
+		);
+	}
+
+	#[test]
+	fn struct_fields() {
+		let fields = [
+			ParamType::Uint(128),
+			ParamType::Uint(128),
+			ParamType::Address,
+			ParamType::Bool,
+			ParamType::Uint(256),
+			ParamType::FixedArray(Box::new(ParamType::Uint(8)), 40),
+			ParamType::Bool,
+			ParamType::String,
+		];
+		let expected = vec![
+			StorageLocation { slot: 0.into(), offset: 0 },
+			StorageLocation { slot: 0.into(), offset: 16 },
+			StorageLocation { slot: 1.into(), offset: 0 },
+			StorageLocation { slot: 1.into(), offset: 20 },
+			StorageLocation { slot: 2.into(), offset: 0 },
+			StorageLocation { slot: 3.into(), offset: 0 },
+			StorageLocation { slot: 5.into(), offset: 0 },
+			StorageLocation { slot: 6.into(), offset: 0 },
+		];
+		assert_eq!(struct_layout(&fields), expected);
+		assert_eq!(storage_slots(&ParamType::Tuple(fields.to_vec())), 7);
+		assert_eq!(struct_field(10.into(), &fields, 3), Some(StorageLocation { slot: 11.into(), offset: 20 }));
+		assert_eq!(struct_field(10.into(), &fields, 8), None);
+	}
+}
@@ -0,0 +1,356 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Conversion between tokens and plain JSON values.
+//!
+//! Integers are decimal strings, `bytes` and `bytesN` 0x-prefixed hex strings and arrays JSON
+//! arrays. Tuples are objects keyed by component names, or arrays if all components are unnamed.
+
+use serde_json::{Map, Value};
+
+use crate::{
+	encoder::check_int_range, event::is_hashed_topic, Error, EventParam, Param, ParamType, Token, TupleParam, Uint,
+};
+
+/// Converts a JSON value to a token of the given type.
+pub fn token_from_json(kind: &ParamType, value: &Value) -> Result<Token, Error> {
+	from_json(kind, &[], value)
+}
+
+/// Converts a token of the given type to a JSON value.
+pub fn token_to_json(kind: &ParamType, token: &Token) -> Result<Value, Error> {
+	to_json(kind, &[], token)
+}
+
+/// Converts a JSON object keyed by param names, or an array in param order, to tokens.
+pub fn tokens_from_json(params: &[Param], value: &Value) -> Result<Vec<Token>, Error> {
+	let fields = params.iter().map(|param| (param.name.as_str(), &param.kind, param.components.as_slice()));
+	fields_from_json(fields, value)
+}
+
+/// Converts tokens to a JSON object keyed by param names, or to an array if all params are
+/// unnamed. Fails if only some params are named or names are not unique.
+pub fn tokens_to_json(params: &[Param], tokens: &[Token]) -> Result<Value, Error> {
+	let fields = params.iter().map(|param| (param.name.as_str(), &param.kind, param.components.as_slice()));
+	fields_to_json(fields, tokens)
+}
+
+/// Converts the token of an event param to a JSON value, naming tuple fields after the param components.
+pub(crate) fn event_param_to_json(param: &EventParam, token: &Token) -> Result<Value, Error> {
+	to_json(&param.kind, &param.components, token)
+}
+
+fn invalid(kind: &ParamType, value: &Value) -> Error {
+	Error::Other(format!("invalid {kind} value: {value}").into())
+}
+
+fn from_json(kind: &ParamType, components: &[TupleParam], value: &Value) -> Result<Token, Error> {
+	let token = match (kind, value) {
+		(ParamType::Address, Value::String(s)) => {
+			let address = hex::decode(s.strip_prefix("0x").unwrap_or(s))?;
+			if address.len() != 20 {
+				return Err(invalid(kind, value));
+			}
+			Token::Address(crate::Address::from_slice(&address))
+		}
+		(ParamType::Bool, Value::Bool(b)) => Token::Bool(*b),
+		(ParamType::String, Value::String(s)) => Token::String(s.clone()),
+		(ParamType::Bytes, Value::String(s)) => Token::Bytes(hex::decode(s.strip_prefix("0x").unwrap_or(s))?),
+		(ParamType::FixedBytes(size), Value::String(s)) => {
+			let bytes = hex::decode(s.strip_prefix("0x").unwrap_or(s))?;
+			if bytes.len() != *size {
+				return Err(invalid(kind, value));
+			}
+			Token::FixedBytes(bytes)
+		}
+		(ParamType::Uint(_), _) => Token::Uint(uint_from_json(value).ok_or_else(|| invalid(kind, value))?),
+		(ParamType::Int(_), _) => Token::Int(int_from_json(value).ok_or_else(|| invalid(kind, value))?),
+		(ParamType::Array(inner), Value::Array(values)) => {
+			Token::Array(values.iter().map(|value| from_json(inner, components, value)).collect::<Result<_, _>>()?)
+		}
+		(ParamType::FixedArray(inner, size), Value::Array(values)) if values.len() == *size => {
+			Token::FixedArray(values.iter().map(|value| from_json(inner, components, value)).collect::<Result<_, _>>()?)
+		}
+		(ParamType::Tuple(kinds), _) => Token::Tuple(fields_from_json(tuple_fields(kinds, components), value)?),
+		_ => return Err(invalid(kind, value)),
+	};
+
+	if let Token::Uint(value) | Token::Int(value) = token {
+		check_int_range(kind, value)?;
+	}
+	Ok(token)
+}
+
+fn to_json(kind: &ParamType, components: &[TupleParam], token: &Token) -> Result<Value, Error> {
+	if let Token::HashedTopic(hash) = token {
+		return match is_hashed_topic(kind) {
+			true => Ok(Value::String(format!("{hash:?}"))),
+			false => Err(Error::InvalidData),
+		};
+	}
+	if !token.type_check(kind) {
+		return Err(Error::InvalidData);
+	}
+
+	let value = match (kind, token) {
+		(_, Token::Address(address)) => Value::String(format!("{address:?}")),
+		(_, Token::Bool(b)) => Value::Bool(*b),
+		(_, Token::String(s)) => Value::String(s.clone()),
+		(_, Token::Bytes(bytes)) | (_, Token::FixedBytes(bytes)) => Value::String(format!("0x{}", hex::encode(bytes))),
+		(_, Token::Uint(uint)) => Value::String(uint.to_string()),
+		(_, Token::Int(int)) if int.bit(255) => Value::String(format!("-{}", !*int + 1)),
+		(_, Token::Int(int)) => Value::String(int.to_string()),
+		(ParamType::Array(inner), Token::Array(tokens))
+		| (ParamType::FixedArray(inner, _), Token::FixedArray(tokens)) => {
+			Value::Array(tokens.iter().map(|token| to_json(inner, components, token)).collect::<Result<_, _>>()?)
+		}
+		(ParamType::Tuple(kinds), Token::Tuple(tokens)) => fields_to_json(tuple_fields(kinds, components), tokens)?,
+		_ => return Err(Error::InvalidData),
+	};
+	Ok(value)
+}
+
+fn uint_from_json(value: &Value) -> Option<Uint> {
+	match *value {
+		Value::Number(ref n) => n.as_u64().map(Uint::from),
+		Value::String(ref s) => match s.strip_prefix("0x") {
+			Some(hex) => Uint::from_str_radix(hex, 16).ok(),
+			None => Uint::from_dec_str(s).ok(),
+		},
+		_ => None,
+	}
+}
+
+fn int_from_json(value: &Value) -> Option<Uint> {
+	let (negative, abs) = match *value {
+		Value::Number(ref n) => {
+			let n = n.as_i64()?;
+			(n < 0, Uint::from(n.unsigned_abs()))
+		}
+		Value::String(ref s) => match s.strip_prefix('-') {
+			Some(abs) => (true, uint_from_json(&Value::String(abs.to_owned()))?),
+			None => (false, uint_from_json(value)?),
+		},
+		_ => return None,
+	};
+
+	// two's complement, `check_int_range` rejects values overflowing the type
+	match negative && !abs.is_zero() {
+		true if abs > Uint::one() << 255 => None,
+		true => Some(!abs + 1),
+		false if abs.bit(255) => None,
+		false => Some(abs),
+	}
+}
+
+/// Returns the fields of a tuple, named after `components` if they are known.
+fn tuple_fields<'a>(
+	kinds: &'a [ParamType],
+	components: &'a [TupleParam],
+) -> impl Iterator<Item = (&'a str, &'a ParamType, &'a [TupleParam])> + Clone {
+	kinds.iter().enumerate().map(move |(i, kind)| match components.get(i) {
+		Some(component) => (component.name.as_deref().unwrap_or(""), kind, component.components.as_slice()),
+		None => ("", kind, &[][..]),
+	})
+}
+
+fn fields_from_json<'a, I>(fields: I, value: &Value) -> Result<Vec<Token>, Error>
+where
+	I: Iterator<Item = (&'a str, &'a ParamType, &'a [TupleParam])> + Clone,
+{
+	match *value {
+		Value::Array(ref values) => {
+			if values.len() != fields.clone().count() {
+				return Err(Error::Other(format!("expected {} values: {value}", fields.count()).into()));
+			}
+			fields.zip(values).map(|((_, kind, components), value)| from_json(kind, components, value)).collect()
+		}
+		Value::Object(ref map) => {
+			if let Some(key) = map.keys().find(|key| !fields.clone().any(|(name, _, _)| name == key.as_str())) {
+				return Err(Error::Other(format!("unknown field `{key}`: {value}").into()));
+			}
+			fields
+				.map(|(name, kind, components)| match map.get(name) {
+					Some(value) if !name.is_empty() => from_json(kind, components, value),
+					_ => Err(Error::Other(format!("missing value of `{name}`: {value}").into())),
+				})
+				.collect()
+		}
+		_ => Err(Error::Other(format!("expected an array or object: {value}").into())),
+	}
+}
+
+fn fields_to_json<'a, I>(fields: I, tokens: &[Token]) -> Result<Value, Error>
+where
+	I: Iterator<Item = (&'a str, &'a ParamType, &'a [TupleParam])> + Clone,
+{
+	if fields.clone().count() != tokens.len() {
+		return Err(Error::InvalidData);
+	}
+
+	if fields.clone().all(|(name, _, _)| name.is_empty()) {
+		let values = fields
+			.zip(tokens)
+			.map(|((_, kind, components), token)| to_json(kind, components, token))
+			.collect::<Result<_, _>>()?;
+		return Ok(Value::Array(values));
+	}
+
+	let mut map = Map::new();
+	for ((name, kind, components), token) in fields.zip(tokens) {
+		if name.is_empty() || map.contains_key(name) {
+			return Err(Error::Other(format!("cannot key a value by the empty or duplicate name `{name}`").into()));
+		}
+		map.insert(name.to_owned(), to_json(kind, components, token)?);
+	}
+	Ok(Value::Object(map))
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_json::json;
+
+	use super::{token_from_json, token_to_json, tokens_from_json, tokens_to_json};
+	use crate::{Param, ParamType, Token, TupleParam};
+
+	fn param(name: &str, kind: ParamType) -> Param {
+		Param { name: name.to_owned(), kind, internal_type: None, components: vec![] }
+	}
+
+	fn transfer() -> Vec<Param> {
+		vec![param("to", ParamType::Address), param("amount", ParamType::Uint(256))]
+	}
+
+	#[test]
+	fn value_types() {
+		let cases = vec![
+			(ParamType::Bool, json!(true), Token::Bool(true)),
+			(ParamType::String, json!("gavofyork"), Token::String("gavofyork".to_owned())),
+			(ParamType::Bytes, json!("0x1234"), Token::Bytes(vec![0x12, 0x34])),
+			(ParamType::FixedBytes(2), json!("0x1234"), Token::FixedBytes(vec![0x12, 0x34])),
+			(ParamType::Uint(256), json!("1000"), Token::Uint(1000.into())),
+			(ParamType::Int(8), json!("-1"), Token::Int(!crate::Int::zero())),
+			(ParamType::Int(256), json!("5"), Token::Int(5.into())),
+			(
+				ParamType::Address,
+				json!("0x1111111111111111111111111111111111111111"),
+				Token::Address([0x11u8; 20].into()),
+			),
+			(
+				ParamType::Array(Box::new(ParamType::Uint(8))),
+				json!(["1", "2"]),
+				Token::Array(vec![Token::Uint(1.into()), Token::Uint(2.into())]),
+			),
+		];
+
+		for (kind, value, token) in cases {
+			assert_eq!(token_from_json(&kind, &value).unwrap(), token);
+			assert_eq!(token_to_json(&kind, &token).unwrap(), value);
+		}
+	}
+
+	#[test]
+	fn lenient_integers() {
+		assert_eq!(token_from_json(&ParamType::Uint(256), &json!(1000)).unwrap(), Token::Uint(1000.into()));
+		assert_eq!(token_from_json(&ParamType::Uint(256), &json!("0x3e8")).unwrap(), Token::Uint(1000.into()));
+		assert_eq!(token_from_json(&ParamType::Int(256), &json!(-1)).unwrap(), Token::Int(!crate::Int::zero()));
+	}
+
+	#[test]
+	fn invalid_values() {
+		assert!(token_from_json(&ParamType::Uint(8), &json!("256")).is_err());
+		assert!(token_from_json(&ParamType::Int(8), &json!("-129")).is_err());
+		assert!(token_from_json(&ParamType::Uint(256), &json!(true)).is_err());
+		assert!(token_from_json(&ParamType::Address, &json!("0x1234")).is_err());
+		assert!(token_from_json(&ParamType::FixedArray(Box::new(ParamType::Bool), 2), &json!([true])).is_err());
+		assert!(token_to_json(&ParamType::Bool, &Token::Uint(1.into())).is_err());
+	}
+
+	#[test]
+	fn hashed_topics() {
+		let hash = Token::HashedTopic([0x11u8; 32].into());
+		let value = json!("0x1111111111111111111111111111111111111111111111111111111111111111");
+		assert_eq!(token_to_json(&ParamType::String, &hash).unwrap(), value);
+		assert!(token_to_json(&ParamType::FixedBytes(32), &hash).is_err());
+	}
+
+	#[test]
+	fn params() {
+		let tokens = vec![Token::Address([0x11u8; 20].into()), Token::Uint(1000.into())];
+		let object = json!({ "to": "0x1111111111111111111111111111111111111111", "amount": "1000" });
+		let array = json!(["0x1111111111111111111111111111111111111111", "1000"]);
+
+		assert_eq!(tokens_from_json(&transfer(), &object).unwrap(), tokens);
+		assert_eq!(tokens_from_json(&transfer(), &array).unwrap(), tokens);
+		assert_eq!(tokens_to_json(&transfer(), &tokens).unwrap(), object);
+
+		let unnamed = vec![param("", ParamType::Address), param("", ParamType::Uint(256))];
+		assert_eq!(tokens_to_json(&unnamed, &tokens).unwrap(), array);
+
+		assert!(tokens_from_json(&transfer(), &json!({ "to": "0x1111111111111111111111111111111111111111" })).is_err());
+		assert!(tokens_from_json(&transfer(), &json!(["0x1111111111111111111111111111111111111111"])).is_err());
+	}
+
+	#[test]
+	fn unknown_and_ambiguous_names() {
+		let component = |name: &str, kind| TupleParam {
+			name: Some(name.to_owned()),
+			kind,
+			internal_type: None,
+			components: vec![],
+		};
+		let order = Param {
+			name: "order".to_owned(),
+			kind: ParamType::Tuple(vec![ParamType::Address, ParamType::Bool]),
+			internal_type: None,
+			components: vec![component("maker", ParamType::Address), component("filled", ParamType::Bool)],
+		};
+		let value = json!({ "order": { "maker": "0x1111111111111111111111111111111111111111", "filled": false } });
+		assert!(tokens_from_json(core::slice::from_ref(&order), &value).is_ok());
+		let value =
+			json!({ "order": { "maker": "0x1111111111111111111111111111111111111111", "filled": false, "x": 1 } });
+		assert!(tokens_from_json(core::slice::from_ref(&order), &value).is_err());
+
+		let extra = json!({ "to": "0x1111111111111111111111111111111111111111", "amount": "1000", "memo": "hi" });
+		assert!(tokens_from_json(&transfer(), &extra).is_err());
+
+		let tokens = vec![Token::Address([0x11u8; 20].into()), Token::Uint(1000.into())];
+		let duplicate = vec![param("to", ParamType::Address), param("to", ParamType::Uint(256))];
+		assert!(tokens_to_json(&duplicate, &tokens).is_err());
+		let partly_named = vec![param("to", ParamType::Address), param("", ParamType::Uint(256))];
+		assert!(tokens_to_json(&partly_named, &tokens).is_err());
+	}
+
+	#[test]
+	fn named_tuples() {
+		let component = |name: &str, kind| TupleParam {
+			name: Some(name.to_owned()),
+			kind,
+			internal_type: None,
+			components: vec![],
+		};
+		let orders = Param {
+			name: "orders".to_owned(),
+			kind: ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Address, ParamType::Bool]))),
+			internal_type: None,
+			components: vec![component("maker", ParamType::Address), component("filled", ParamType::Bool)],
+		};
+
+		let value = json!({ "orders": [{ "maker": "0x1111111111111111111111111111111111111111", "filled": false }] });
+		let tokens =
+			vec![Token::Array(vec![Token::Tuple(vec![Token::Address([0x11u8; 20].into()), Token::Bool(false)])])];
+		assert_eq!(tokens_from_json(core::slice::from_ref(&orders), &value).unwrap(), tokens);
+		assert_eq!(tokens_to_json(core::slice::from_ref(&orders), &tokens).unwrap(), value);
+
+		// unnamed tuples are arrays
+		let mut unnamed = orders;
+		unnamed.components.clear();
+		let value = json!({ "orders": [["0x1111111111111111111111111111111111111111", false]] });
+		assert_eq!(tokens_to_json(&[unnamed], &tokens).unwrap(), value);
+	}
+}
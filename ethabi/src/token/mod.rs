@@ -13,6 +13,13 @@ mod lenient;
 #[cfg(feature = "full-serde")]
 pub use lenient::LenientTokenizer;
 
+#[cfg(feature = "full-serde")]
+mod json;
+#[cfg(feature = "full-serde")]
+pub(crate) use json::event_param_to_json;
+#[cfg(feature = "full-serde")]
+pub use json::{token_from_json, token_to_json, tokens_from_json, tokens_to_json};
+
 #[cfg(feature = "full-serde")]
 mod strict;
 #[cfg(feature = "full-serde")]
@@ -81,7 +88,7 @@ pub trait Tokenizer {
 		let mut last_is_array = false;
 
 		let mut params = param.iter();
-		for (pos, ch) in value.chars().enumerate() {
+		for (pos, ch) in value.char_indices() {
 			match ch {
 				'[' if !ignore => {
 					if array_nested == 0 {
@@ -179,7 +186,7 @@ pub trait Tokenizer {
 		let mut tuple_nested = 0isize;
 		let mut tuple_item_start = 1;
 		let mut last_is_tuple = false;
-		for (i, ch) in value.chars().enumerate() {
+		for (i, ch) in value.char_indices() {
 			match ch {
 				'(' if !ignore => {
 					if tuple_nested == 0 {
@@ -288,6 +295,22 @@ mod test {
 		assert!(LenientTokenizer::tokenize_array("[1,0]", &ParamType::Bool).is_ok());
 	}
 
+	#[test]
+	fn non_ascii_in_array_and_tuple() {
+		assert_eq!(
+			LenientTokenizer::tokenize_array("[héllo,wörld]", &ParamType::String).unwrap(),
+			vec![Token::String("héllo".to_owned()), Token::String("wörld".to_owned())]
+		);
+		assert_eq!(
+			LenientTokenizer::tokenize_struct(
+				"([é],ü)",
+				&[ParamType::Array(Box::new(ParamType::String)), ParamType::String]
+			)
+			.unwrap(),
+			vec![Token::Array(vec![Token::String("é".to_owned())]), Token::String("ü".to_owned())]
+		);
+	}
+
 	#[test]
 	fn tuples_arrays_mixed() {
 		assert_eq!(
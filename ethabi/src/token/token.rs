@@ -15,7 +15,7 @@ use serde::{Deserialize, Serialize};
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-use crate::{Address, Bytes, FixedBytes, Int, ParamType, Uint};
+use crate::{Address, Bytes, FixedBytes, Hash, Int, ParamType, Uint};
 
 /// Ethereum ABI params.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -69,6 +69,12 @@ pub enum Token {
 	///
 	/// solidity name: tuple
 	Tuple(Vec<Token>),
+	/// Keccak-256 hash of an indexed event param.
+	///
+	/// Indexed `string`, `bytes`, arrays and structs are stored in log topics only as the hash of
+	/// their encoding, see [`topic_for`](crate::topic_for). It does not type check against any param
+	/// type, so encoding with types fails; untyped [`encode`](crate::encode) writes the topic word.
+	HashedTopic(Hash),
 }
 
 impl fmt::Display for Token {
@@ -89,6 +95,7 @@ impl fmt::Display for Token {
 
 				write!(f, "({s})")
 			}
+			Token::HashedTopic(ref hash) => write!(f, "{}", hex::encode(hash)),
 		}
 	}
 }
@@ -138,6 +145,7 @@ impl Token {
 					false
 				}
 			}
+			Token::HashedTopic(_) => false,
 		}
 	}
 
@@ -221,6 +229,14 @@ impl Token {
 		}
 	}
 
+	/// Converts token to...
+	pub fn into_hashed_topic(self) -> Option<Hash> {
+		match self {
+			Token::HashedTopic(hash) => Some(hash),
+			_ => None,
+		}
+	}
+
 	/// Check if all the types of the tokens match the given parameter types.
 	pub fn types_check(tokens: &[Token], param_types: &[ParamType]) -> bool {
 		param_types.len() == tokens.len() && {
@@ -298,6 +314,9 @@ mod tests {
 			vec![Token::FixedArray(vec![Token::Bool(false), Token::Bool(true)])],
 			vec![ParamType::FixedArray(Box::new(ParamType::Address), 2)],
 		);
+
+		assert_not_type_check(vec![Token::HashedTopic([0u8; 32].into())], vec![ParamType::String]);
+		assert_not_type_check(vec![Token::HashedTopic([0u8; 32].into())], vec![ParamType::FixedBytes(32)]);
 	}
 
 	#[test]
@@ -10,8 +10,12 @@
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-use crate::{param_type::Writer, ParamType};
+#[cfg(feature = "serde")]
+use crate::param_type::Writer;
+use crate::ParamType;
+#[cfg(feature = "serde")]
 use core::fmt;
+#[cfg(feature = "serde")]
 use serde::{
 	de::{Error, MapAccess, Visitor},
 	ser::SerializeMap,
@@ -29,8 +33,25 @@ pub struct TupleParam {
 
 	/// Additional Internal type.
 	pub internal_type: Option<String>,
+
+	/// Components of a tuple param or of the tuples of an array param, empty if unknown.
+	pub components: Vec<TupleParam>,
 }
 
+impl TupleParam {
+	/// Creates a named component without internal type and components.
+	pub fn new<S: Into<String>>(name: S, kind: ParamType) -> Self {
+		TupleParam { name: Some(name.into()), kind, internal_type: None, components: Vec::new() }
+	}
+
+	/// Sets the components of a tuple component or of the tuples of an array component.
+	pub fn with_components(mut self, components: Vec<TupleParam>) -> Self {
+		self.components = components;
+		self
+	}
+}
+
+#[cfg(feature = "serde")]
 impl<'a> Deserialize<'a> for TupleParam {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where
@@ -40,8 +61,10 @@ impl<'a> Deserialize<'a> for TupleParam {
 	}
 }
 
+#[cfg(feature = "serde")]
 struct TupleParamVisitor;
 
+#[cfg(feature = "serde")]
 impl<'a> Visitor<'a> for TupleParamVisitor {
 	type Value = TupleParam;
 
@@ -90,11 +113,12 @@ impl<'a> Visitor<'a> for TupleParamVisitor {
 		}
 
 		let mut kind = kind.ok_or_else(|| Error::missing_field("kind"))?;
-		crate::param::set_tuple_components(&mut kind, components)?;
-		Ok(TupleParam { name, kind, internal_type })
+		let components = crate::param::set_tuple_components(&mut kind, components)?;
+		Ok(TupleParam { name, kind, internal_type, components })
 	}
 }
 
+#[cfg(feature = "serde")]
 impl Serialize for TupleParam {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 	where
@@ -108,15 +132,12 @@ impl Serialize for TupleParam {
 			map.serialize_entry("name", name)?;
 		}
 		map.serialize_entry("type", &Writer::write_for_abi(&self.kind, false))?;
-		if let Some(inner_tuple) = crate::param::inner_tuple(&self.kind) {
-			map.serialize_key("components")?;
-			map.serialize_value(&crate::param::SerializeableParamVec(inner_tuple))?;
-		}
+		crate::param::serialize_components(&mut map, &self.kind, &self.components)?;
 		map.end()
 	}
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde"))]
 mod tests {
 	#[cfg(not(feature = "std"))]
 	use crate::no_std_prelude::*;
@@ -136,7 +157,12 @@ mod tests {
 
 		assert_eq!(
 			deserialized,
-			TupleParam { name: Some("foo".to_owned()), kind: ParamType::Address, internal_type: None }
+			TupleParam {
+				name: Some("foo".to_owned()),
+				kind: ParamType::Address,
+				internal_type: None,
+				components: vec![]
+			}
 		);
 
 		assert_json_eq(s, serde_json::to_string(&deserialized).unwrap().as_str());
@@ -157,7 +183,8 @@ mod tests {
 			TupleParam {
 				name: Some("foo".to_owned()),
 				kind: ParamType::Address,
-				internal_type: Some("struct Verifier.Proof".to_string())
+				internal_type: Some("struct Verifier.Proof".to_string()),
+				components: vec![]
 			}
 		);
 
@@ -172,7 +199,10 @@ mod tests {
 
 		let deserialized: TupleParam = serde_json::from_str(s).unwrap();
 
-		assert_eq!(deserialized, TupleParam { name: None, kind: ParamType::Address, internal_type: None });
+		assert_eq!(
+			deserialized,
+			TupleParam { name: None, kind: ParamType::Address, internal_type: None, components: vec![] }
+		);
 
 		assert_json_eq(s, serde_json::to_string(&deserialized).unwrap().as_str());
 	}
@@ -203,7 +233,21 @@ mod tests {
 			TupleParam {
 				name: None,
 				kind: ParamType::Tuple(vec![ParamType::Uint(48), ParamType::Tuple(vec![ParamType::Address])]),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					TupleParam { name: None, kind: ParamType::Uint(48), internal_type: None, components: vec![] },
+					TupleParam {
+						name: None,
+						kind: ParamType::Tuple(vec![ParamType::Address]),
+						internal_type: None,
+						components: vec![TupleParam {
+							name: None,
+							kind: ParamType::Address,
+							internal_type: None,
+							components: vec![]
+						}]
+					}
+				]
 			}
 		);
 
@@ -239,7 +283,26 @@ mod tests {
 			TupleParam {
 				name: None,
 				kind: ParamType::Tuple(vec![ParamType::Uint(48), ParamType::Tuple(vec![ParamType::Address])]),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					TupleParam {
+						name: Some("amount".to_owned()),
+						kind: ParamType::Uint(48),
+						internal_type: None,
+						components: vec![]
+					},
+					TupleParam {
+						name: Some("things".to_owned()),
+						kind: ParamType::Tuple(vec![ParamType::Address]),
+						internal_type: None,
+						components: vec![TupleParam {
+							name: Some("baseTupleParam".to_owned()),
+							kind: ParamType::Address,
+							internal_type: None,
+							components: vec![]
+						}]
+					}
+				]
 			}
 		);
 
@@ -274,7 +337,12 @@ mod tests {
 					ParamType::Address,
 					ParamType::Address
 				]))),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					TupleParam { name: None, kind: ParamType::Uint(48), internal_type: None, components: vec![] },
+					TupleParam { name: None, kind: ParamType::Address, internal_type: None, components: vec![] },
+					TupleParam { name: None, kind: ParamType::Address, internal_type: None, components: vec![] }
+				]
 			}
 		);
 
@@ -304,7 +372,11 @@ mod tests {
 					ParamType::Uint(8),
 					ParamType::Uint(16),
 				]))))),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					TupleParam { name: None, kind: ParamType::Uint(8), internal_type: None, components: vec![] },
+					TupleParam { name: None, kind: ParamType::Uint(16), internal_type: None, components: vec![] }
+				]
 			}
 		);
 
@@ -338,7 +410,12 @@ mod tests {
 					Box::new(ParamType::Tuple(vec![ParamType::Uint(48), ParamType::Address, ParamType::Address])),
 					2
 				),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					TupleParam { name: None, kind: ParamType::Uint(48), internal_type: None, components: vec![] },
+					TupleParam { name: None, kind: ParamType::Address, internal_type: None, components: vec![] },
+					TupleParam { name: None, kind: ParamType::Address, internal_type: None, components: vec![] }
+				]
 			}
 		);
 
@@ -379,7 +456,31 @@ mod tests {
 					ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Address]))),
 					ParamType::FixedArray(Box::new(ParamType::Tuple(vec![ParamType::Address])), 42,)
 				]),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					TupleParam {
+						name: None,
+						kind: ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Address]))),
+						internal_type: None,
+						components: vec![TupleParam {
+							name: None,
+							kind: ParamType::Address,
+							internal_type: None,
+							components: vec![]
+						}]
+					},
+					TupleParam {
+						name: None,
+						kind: ParamType::FixedArray(Box::new(ParamType::Tuple(vec![ParamType::Address])), 42),
+						internal_type: None,
+						components: vec![TupleParam {
+							name: None,
+							kind: ParamType::Address,
+							internal_type: None,
+							components: vec![]
+						}]
+					}
+				]
 			}
 		);
 
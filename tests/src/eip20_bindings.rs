@@ -0,0 +1,515 @@
+// Generated by `ethabi codegen` from `eip20.abi`, do not edit.
+
+#![allow(
+    clippy::all,
+    deprecated,
+    dead_code,
+    missing_docs,
+    unused_imports,
+    unused_mut,
+    unused_variables
+)]
+use ethabi;
+const INTERNAL_ERR: &'static str = "`ethabi_derive` internal error";
+/// Contract's functions.
+pub mod functions {
+    use super::INTERNAL_ERR;
+    pub mod allowance {
+        use ethabi;
+        use super::INTERNAL_ERR;
+        fn function() -> ethabi::Function {
+            ethabi::Function {
+                name: "allowance".into(),
+                inputs: vec![
+                    ethabi::Param { name : "_owner".to_owned(), kind :
+                    ethabi::ParamType::Address, internal_type : None, components : vec![]
+                    }, ethabi::Param { name : "_spender".to_owned(), kind :
+                    ethabi::ParamType::Address, internal_type : None, components : vec![]
+                    }
+                ],
+                outputs: vec![
+                    ethabi::Param { name : "remaining".to_owned(), kind :
+                    ethabi::ParamType::Uint(256usize), internal_type : None, components :
+                    vec![] }
+                ],
+                constant: Some(true),
+                state_mutability: ::ethabi::StateMutability::NonPayable,
+            }
+        }
+        /// Generic function output decoder.
+        pub struct Decoder(ethabi::Function);
+        impl ethabi::FunctionOutputDecoder for Decoder {
+            type Output = ethabi::Uint;
+            fn decode(&self, output: &[u8]) -> ethabi::Result<Self::Output> {
+                let out = self
+                    .0
+                    .decode_output(output)?
+                    .into_iter()
+                    .next()
+                    .expect(INTERNAL_ERR);
+                Ok(out.into_uint().expect(INTERNAL_ERR))
+            }
+        }
+        /// Encodes function input.
+        pub fn encode_input<T0: Into<ethabi::Address>, T1: Into<ethabi::Address>>(
+            owner: T0,
+            spender: T1,
+        ) -> ethabi::Bytes {
+            let f = function();
+            let tokens = vec![
+                ethabi::Token::Address(owner.into()), ethabi::Token::Address(spender
+                .into())
+            ];
+            f.encode_input(&tokens).expect(INTERNAL_ERR)
+        }
+        /// Decodes function output.
+        pub fn decode_output(output: &[u8]) -> ethabi::Result<ethabi::Uint> {
+            ethabi::FunctionOutputDecoder::decode(&Decoder(function()), output)
+        }
+        /// Encodes function output and creates a `Decoder` instance.
+        pub fn call<T0: Into<ethabi::Address>, T1: Into<ethabi::Address>>(
+            owner: T0,
+            spender: T1,
+        ) -> (ethabi::Bytes, Decoder) {
+            let f = function();
+            let tokens = vec![
+                ethabi::Token::Address(owner.into()), ethabi::Token::Address(spender
+                .into())
+            ];
+            (f.encode_input(&tokens).expect(INTERNAL_ERR), Decoder(f))
+        }
+    }
+    pub mod approve {
+        use ethabi;
+        use super::INTERNAL_ERR;
+        fn function() -> ethabi::Function {
+            ethabi::Function {
+                name: "approve".into(),
+                inputs: vec![
+                    ethabi::Param { name : "_spender".to_owned(), kind :
+                    ethabi::ParamType::Address, internal_type : None, components : vec![]
+                    }, ethabi::Param { name : "_value".to_owned(), kind :
+                    ethabi::ParamType::Uint(256usize), internal_type : None, components :
+                    vec![] }
+                ],
+                outputs: vec![
+                    ethabi::Param { name : "success".to_owned(), kind :
+                    ethabi::ParamType::Bool, internal_type : None, components : vec![] }
+                ],
+                constant: Some(false),
+                state_mutability: ::ethabi::StateMutability::NonPayable,
+            }
+        }
+        /// Generic function output decoder.
+        pub struct Decoder(ethabi::Function);
+        impl ethabi::FunctionOutputDecoder for Decoder {
+            type Output = bool;
+            fn decode(&self, output: &[u8]) -> ethabi::Result<Self::Output> {
+                let out = self
+                    .0
+                    .decode_output(output)?
+                    .into_iter()
+                    .next()
+                    .expect(INTERNAL_ERR);
+                Ok(out.into_bool().expect(INTERNAL_ERR))
+            }
+        }
+        /// Encodes function input.
+        pub fn encode_input<T0: Into<ethabi::Address>, T1: Into<ethabi::Uint>>(
+            spender: T0,
+            value: T1,
+        ) -> ethabi::Bytes {
+            let f = function();
+            let tokens = vec![
+                ethabi::Token::Address(spender.into()), ethabi::Token::Uint(value.into())
+            ];
+            f.encode_input(&tokens).expect(INTERNAL_ERR)
+        }
+        /// Decodes function output.
+        pub fn decode_output(output: &[u8]) -> ethabi::Result<bool> {
+            ethabi::FunctionOutputDecoder::decode(&Decoder(function()), output)
+        }
+        /// Encodes function output and creates a `Decoder` instance.
+        pub fn call<T0: Into<ethabi::Address>, T1: Into<ethabi::Uint>>(
+            spender: T0,
+            value: T1,
+        ) -> (ethabi::Bytes, Decoder) {
+            let f = function();
+            let tokens = vec![
+                ethabi::Token::Address(spender.into()), ethabi::Token::Uint(value.into())
+            ];
+            (f.encode_input(&tokens).expect(INTERNAL_ERR), Decoder(f))
+        }
+    }
+    pub mod balance_of {
+        use ethabi;
+        use super::INTERNAL_ERR;
+        fn function() -> ethabi::Function {
+            ethabi::Function {
+                name: "balanceOf".into(),
+                inputs: vec![
+                    ethabi::Param { name : "_owner".to_owned(), kind :
+                    ethabi::ParamType::Address, internal_type : None, components : vec![]
+                    }
+                ],
+                outputs: vec![
+                    ethabi::Param { name : "balance".to_owned(), kind :
+                    ethabi::ParamType::Uint(256usize), internal_type : None, components :
+                    vec![] }
+                ],
+                constant: Some(true),
+                state_mutability: ::ethabi::StateMutability::NonPayable,
+            }
+        }
+        /// Generic function output decoder.
+        pub struct Decoder(ethabi::Function);
+        impl ethabi::FunctionOutputDecoder for Decoder {
+            type Output = ethabi::Uint;
+            fn decode(&self, output: &[u8]) -> ethabi::Result<Self::Output> {
+                let out = self
+                    .0
+                    .decode_output(output)?
+                    .into_iter()
+                    .next()
+                    .expect(INTERNAL_ERR);
+                Ok(out.into_uint().expect(INTERNAL_ERR))
+            }
+        }
+        /// Encodes function input.
+        pub fn encode_input<T0: Into<ethabi::Address>>(owner: T0) -> ethabi::Bytes {
+            let f = function();
+            let tokens = vec![ethabi::Token::Address(owner.into())];
+            f.encode_input(&tokens).expect(INTERNAL_ERR)
+        }
+        /// Decodes function output.
+        pub fn decode_output(output: &[u8]) -> ethabi::Result<ethabi::Uint> {
+            ethabi::FunctionOutputDecoder::decode(&Decoder(function()), output)
+        }
+        /// Encodes function output and creates a `Decoder` instance.
+        pub fn call<T0: Into<ethabi::Address>>(owner: T0) -> (ethabi::Bytes, Decoder) {
+            let f = function();
+            let tokens = vec![ethabi::Token::Address(owner.into())];
+            (f.encode_input(&tokens).expect(INTERNAL_ERR), Decoder(f))
+        }
+    }
+    pub mod total_supply {
+        use ethabi;
+        use super::INTERNAL_ERR;
+        fn function() -> ethabi::Function {
+            ethabi::Function {
+                name: "totalSupply".into(),
+                inputs: vec![],
+                outputs: vec![
+                    ethabi::Param { name : "total".to_owned(), kind :
+                    ethabi::ParamType::Uint(256usize), internal_type : None, components :
+                    vec![] }
+                ],
+                constant: Some(true),
+                state_mutability: ::ethabi::StateMutability::NonPayable,
+            }
+        }
+        /// Generic function output decoder.
+        pub struct Decoder(ethabi::Function);
+        impl ethabi::FunctionOutputDecoder for Decoder {
+            type Output = ethabi::Uint;
+            fn decode(&self, output: &[u8]) -> ethabi::Result<Self::Output> {
+                let out = self
+                    .0
+                    .decode_output(output)?
+                    .into_iter()
+                    .next()
+                    .expect(INTERNAL_ERR);
+                Ok(out.into_uint().expect(INTERNAL_ERR))
+            }
+        }
+        /// Encodes function input.
+        pub fn encode_input() -> ethabi::Bytes {
+            let f = function();
+            let tokens = vec![];
+            f.encode_input(&tokens).expect(INTERNAL_ERR)
+        }
+        /// Decodes function output.
+        pub fn decode_output(output: &[u8]) -> ethabi::Result<ethabi::Uint> {
+            ethabi::FunctionOutputDecoder::decode(&Decoder(function()), output)
+        }
+        /// Encodes function output and creates a `Decoder` instance.
+        pub fn call() -> (ethabi::Bytes, Decoder) {
+            let f = function();
+            let tokens = vec![];
+            (f.encode_input(&tokens).expect(INTERNAL_ERR), Decoder(f))
+        }
+    }
+    pub mod transfer {
+        use ethabi;
+        use super::INTERNAL_ERR;
+        fn function() -> ethabi::Function {
+            ethabi::Function {
+                name: "transfer".into(),
+                inputs: vec![
+                    ethabi::Param { name : "_to".to_owned(), kind :
+                    ethabi::ParamType::Address, internal_type : None, components : vec![]
+                    }, ethabi::Param { name : "_value".to_owned(), kind :
+                    ethabi::ParamType::Uint(256usize), internal_type : None, components :
+                    vec![] }
+                ],
+                outputs: vec![
+                    ethabi::Param { name : "success".to_owned(), kind :
+                    ethabi::ParamType::Bool, internal_type : None, components : vec![] }
+                ],
+                constant: Some(false),
+                state_mutability: ::ethabi::StateMutability::NonPayable,
+            }
+        }
+        /// Generic function output decoder.
+        pub struct Decoder(ethabi::Function);
+        impl ethabi::FunctionOutputDecoder for Decoder {
+            type Output = bool;
+            fn decode(&self, output: &[u8]) -> ethabi::Result<Self::Output> {
+                let out = self
+                    .0
+                    .decode_output(output)?
+                    .into_iter()
+                    .next()
+                    .expect(INTERNAL_ERR);
+                Ok(out.into_bool().expect(INTERNAL_ERR))
+            }
+        }
+        /// Encodes function input.
+        pub fn encode_input<T0: Into<ethabi::Address>, T1: Into<ethabi::Uint>>(
+            to: T0,
+            value: T1,
+        ) -> ethabi::Bytes {
+            let f = function();
+            let tokens = vec![
+                ethabi::Token::Address(to.into()), ethabi::Token::Uint(value.into())
+            ];
+            f.encode_input(&tokens).expect(INTERNAL_ERR)
+        }
+        /// Decodes function output.
+        pub fn decode_output(output: &[u8]) -> ethabi::Result<bool> {
+            ethabi::FunctionOutputDecoder::decode(&Decoder(function()), output)
+        }
+        /// Encodes function output and creates a `Decoder` instance.
+        pub fn call<T0: Into<ethabi::Address>, T1: Into<ethabi::Uint>>(
+            to: T0,
+            value: T1,
+        ) -> (ethabi::Bytes, Decoder) {
+            let f = function();
+            let tokens = vec![
+                ethabi::Token::Address(to.into()), ethabi::Token::Uint(value.into())
+            ];
+            (f.encode_input(&tokens).expect(INTERNAL_ERR), Decoder(f))
+        }
+    }
+    pub mod transfer_from {
+        use ethabi;
+        use super::INTERNAL_ERR;
+        fn function() -> ethabi::Function {
+            ethabi::Function {
+                name: "transferFrom".into(),
+                inputs: vec![
+                    ethabi::Param { name : "_from".to_owned(), kind :
+                    ethabi::ParamType::Address, internal_type : None, components : vec![]
+                    }, ethabi::Param { name : "_to".to_owned(), kind :
+                    ethabi::ParamType::Address, internal_type : None, components : vec![]
+                    }, ethabi::Param { name : "_value".to_owned(), kind :
+                    ethabi::ParamType::Uint(256usize), internal_type : None, components :
+                    vec![] }
+                ],
+                outputs: vec![
+                    ethabi::Param { name : "success".to_owned(), kind :
+                    ethabi::ParamType::Bool, internal_type : None, components : vec![] }
+                ],
+                constant: Some(false),
+                state_mutability: ::ethabi::StateMutability::NonPayable,
+            }
+        }
+        /// Generic function output decoder.
+        pub struct Decoder(ethabi::Function);
+        impl ethabi::FunctionOutputDecoder for Decoder {
+            type Output = bool;
+            fn decode(&self, output: &[u8]) -> ethabi::Result<Self::Output> {
+                let out = self
+                    .0
+                    .decode_output(output)?
+                    .into_iter()
+                    .next()
+                    .expect(INTERNAL_ERR);
+                Ok(out.into_bool().expect(INTERNAL_ERR))
+            }
+        }
+        /// Encodes function input.
+        pub fn encode_input<
+            T0: Into<ethabi::Address>,
+            T1: Into<ethabi::Address>,
+            T2: Into<ethabi::Uint>,
+        >(from: T0, to: T1, value: T2) -> ethabi::Bytes {
+            let f = function();
+            let tokens = vec![
+                ethabi::Token::Address(from.into()), ethabi::Token::Address(to.into()),
+                ethabi::Token::Uint(value.into())
+            ];
+            f.encode_input(&tokens).expect(INTERNAL_ERR)
+        }
+        /// Decodes function output.
+        pub fn decode_output(output: &[u8]) -> ethabi::Result<bool> {
+            ethabi::FunctionOutputDecoder::decode(&Decoder(function()), output)
+        }
+        /// Encodes function output and creates a `Decoder` instance.
+        pub fn call<
+            T0: Into<ethabi::Address>,
+            T1: Into<ethabi::Address>,
+            T2: Into<ethabi::Uint>,
+        >(from: T0, to: T1, value: T2) -> (ethabi::Bytes, Decoder) {
+            let f = function();
+            let tokens = vec![
+                ethabi::Token::Address(from.into()), ethabi::Token::Address(to.into()),
+                ethabi::Token::Uint(value.into())
+            ];
+            (f.encode_input(&tokens).expect(INTERNAL_ERR), Decoder(f))
+        }
+    }
+}
+/// Contract's events.
+pub mod events {
+    use super::INTERNAL_ERR;
+    pub mod approval {
+        use ethabi;
+        use super::INTERNAL_ERR;
+        pub fn event() -> ethabi::Event {
+            ethabi::Event {
+                name: "Approval".into(),
+                inputs: vec![
+                    ethabi::EventParam { name : "owner".to_owned(), kind :
+                    ethabi::ParamType::Address, indexed : true, components : vec![] },
+                    ethabi::EventParam { name : "spender".to_owned(), kind :
+                    ethabi::ParamType::Address, indexed : true, components : vec![] },
+                    ethabi::EventParam { name : "value".to_owned(), kind :
+                    ethabi::ParamType::Uint(256usize), indexed : false, components :
+                    vec![] }
+                ],
+                anonymous: false,
+            }
+        }
+        pub fn filter<
+            T0: Into<ethabi::Topic<ethabi::Address>>,
+            T1: Into<ethabi::Topic<ethabi::Address>>,
+        >(owner: T0, spender: T1) -> ethabi::TopicFilter {
+            let raw = ethabi::RawTopicFilter {
+                topic0: owner.into().map(|i| ethabi::Token::Address(i)),
+                topic1: spender.into().map(|i| ethabi::Token::Address(i)),
+                ..Default::default()
+            };
+            let e = event();
+            e.filter(raw).expect(INTERNAL_ERR)
+        }
+        pub fn wildcard_filter() -> ethabi::TopicFilter {
+            filter(ethabi::Topic::Any, ethabi::Topic::Any)
+        }
+        pub fn parse_log(
+            log: ethabi::RawLog,
+        ) -> ethabi::Result<super::super::logs::Approval> {
+            let e = event();
+            let mut log = e.parse_log(log)?.params.into_iter();
+            let result = super::super::logs::Approval {
+                owner: log
+                    .next()
+                    .expect(INTERNAL_ERR)
+                    .value
+                    .into_address()
+                    .expect(INTERNAL_ERR),
+                spender: log
+                    .next()
+                    .expect(INTERNAL_ERR)
+                    .value
+                    .into_address()
+                    .expect(INTERNAL_ERR),
+                value: log
+                    .next()
+                    .expect(INTERNAL_ERR)
+                    .value
+                    .into_uint()
+                    .expect(INTERNAL_ERR),
+            };
+            Ok(result)
+        }
+    }
+    pub mod transfer {
+        use ethabi;
+        use super::INTERNAL_ERR;
+        pub fn event() -> ethabi::Event {
+            ethabi::Event {
+                name: "Transfer".into(),
+                inputs: vec![
+                    ethabi::EventParam { name : "from".to_owned(), kind :
+                    ethabi::ParamType::Address, indexed : true, components : vec![] },
+                    ethabi::EventParam { name : "to".to_owned(), kind :
+                    ethabi::ParamType::Address, indexed : true, components : vec![] },
+                    ethabi::EventParam { name : "value".to_owned(), kind :
+                    ethabi::ParamType::Uint(256usize), indexed : false, components :
+                    vec![] }
+                ],
+                anonymous: false,
+            }
+        }
+        pub fn filter<
+            T0: Into<ethabi::Topic<ethabi::Address>>,
+            T1: Into<ethabi::Topic<ethabi::Address>>,
+        >(from: T0, to: T1) -> ethabi::TopicFilter {
+            let raw = ethabi::RawTopicFilter {
+                topic0: from.into().map(|i| ethabi::Token::Address(i)),
+                topic1: to.into().map(|i| ethabi::Token::Address(i)),
+                ..Default::default()
+            };
+            let e = event();
+            e.filter(raw).expect(INTERNAL_ERR)
+        }
+        pub fn wildcard_filter() -> ethabi::TopicFilter {
+            filter(ethabi::Topic::Any, ethabi::Topic::Any)
+        }
+        pub fn parse_log(
+            log: ethabi::RawLog,
+        ) -> ethabi::Result<super::super::logs::Transfer> {
+            let e = event();
+            let mut log = e.parse_log(log)?.params.into_iter();
+            let result = super::super::logs::Transfer {
+                from: log
+                    .next()
+                    .expect(INTERNAL_ERR)
+                    .value
+                    .into_address()
+                    .expect(INTERNAL_ERR),
+                to: log
+                    .next()
+                    .expect(INTERNAL_ERR)
+                    .value
+                    .into_address()
+                    .expect(INTERNAL_ERR),
+                value: log
+                    .next()
+                    .expect(INTERNAL_ERR)
+                    .value
+                    .into_uint()
+                    .expect(INTERNAL_ERR),
+            };
+            Ok(result)
+        }
+    }
+}
+/// Contract's logs.
+pub mod logs {
+    use super::INTERNAL_ERR;
+    use ethabi;
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Approval {
+        pub owner: ethabi::Address,
+        pub spender: ethabi::Address,
+        pub value: ethabi::Uint,
+    }
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Transfer {
+        pub from: ethabi::Address,
+        pub to: ethabi::Address,
+        pub value: ethabi::Uint,
+    }
+}
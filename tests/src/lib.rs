@@ -12,10 +12,15 @@ use_contract!(validators, "../res/Validators.abi");
 use_contract!(operations, "../res/Operations.abi");
 use_contract!(urlhint, "../res/urlhint.abi");
 use_contract!(test_rust_keywords, "../res/test_rust_keywords.abi");
+use_contract!(greeter, "../res/Greeter.json");
+
+/// Bindings of `eip20` generated by `ethabi codegen`.
+#[rustfmt::skip]
+pub mod eip20_bindings;
 
 #[cfg(test)]
 mod tests {
-	use crate::{eip20, validators};
+	use crate::{eip20, eip20_bindings, greeter, validators};
 	use ethabi::{Address, Uint};
 	use hex_literal::hex;
 
@@ -28,6 +33,7 @@ mod tests {
 	}
 
 	#[test]
+	#[allow(clippy::useless_conversion)]
 	fn test_encoding_function_input_as_array() {
 		use validators::functions;
 
@@ -62,6 +68,7 @@ mod tests {
 	}
 
 	#[test]
+	#[allow(clippy::useless_conversion)]
 	fn test_encoding_constructor_as_array() {
 		use validators::constructor;
 
@@ -114,4 +121,30 @@ mod tests {
 		let wildcard_filter_sugared = eip20::events::transfer::wildcard_filter();
 		assert_eq!(wildcard_filter, wildcard_filter_sugared);
 	}
+
+	#[test]
+	fn test_contract_from_artifact() {
+		let encoded = greeter::functions::set_greeting::encode_input("hi");
+		let expected = "a4136862000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000026869000000000000000000000000000000000000000000000000000000000000";
+		assert_eq!(expected, hex::encode(encoded));
+
+		let output = hex!(
+			"
+			0000000000000000000000000000000000000000000000000000000000000020
+			0000000000000000000000000000000000000000000000000000000000000002
+			6869000000000000000000000000000000000000000000000000000000000000
+			"
+		);
+		assert_eq!(greeter::functions::greet::decode_output(&output).unwrap(), "hi");
+	}
+
+	#[test]
+	fn test_generated_bindings() {
+		let to = [0x11u8; 20];
+		let encoded = eip20_bindings::functions::transfer::encode_input(to, 1000);
+		assert_eq!(encoded, eip20::functions::transfer::encode_input(to, 1000));
+
+		let output = hex!("00000000000000000000000000000000000000000000000000000000000003e8");
+		assert_eq!(eip20_bindings::functions::balance_of::decode_output(&output).unwrap(), 1000.into());
+	}
 }
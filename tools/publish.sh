@@ -3,7 +3,7 @@
 set -exu
 
 VERSION=$(grep "^version" ./ethabi/Cargo.toml | sed -e 's/.*"\(.*\)"/\1/')
-ORDER=(ethabi derive contract cli)
+ORDER=(ethabi codegen derive contract cli)
 
 echo "Publishing $VERSION"
 cargo clean